@@ -24,7 +24,7 @@
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use uor_ontology::Ontology;
+use uor_ontology::{iris, Ontology};
 
 /// `cargo uor` — UOR Foundation tooling.
 #[derive(Parser, Debug)]
@@ -365,5 +365,5 @@ fn resolve_iri(iri: &str) -> Result<String> {
     let (prefix, local) = iri
         .split_once(':')
         .with_context(|| format!("expected `prefix:Local` form, got `{iri}`"))?;
-    Ok(format!("https://uor.foundation/{prefix}/{local}"))
+    Ok(iris::iri_for(prefix, local))
 }
@@ -7,7 +7,7 @@
 //!
 //! **Usage:**
 //! ```
-//! uor-crate [--out <path>] [--sdk-out <path>]
+//! uor-crate [--out <path>] [--sdk-out <path>] [--bundle <file>]
 //! ```
 
 #![deny(
@@ -30,7 +30,9 @@ use clap::Parser;
     about = "Generate the uor-foundation Rust trait crate"
 )]
 struct Args {
-    /// Output directory for generated foundation source files.
+    /// Output directory for generated foundation source files. With
+    /// `--bundle`, the directory whose `@codegen-exempt` sources are inlined
+    /// into the bundle.
     #[arg(long, default_value = "foundation/src")]
     out: PathBuf,
 
@@ -39,6 +41,11 @@ struct Args {
     /// so the two emitted crates sit as siblings under the workspace.
     #[arg(long)]
     sdk_out: Option<PathBuf>,
+
+    /// Emit the whole crate as one standalone `.rs` file at this path
+    /// instead of writing the multi-file crate to `--out`.
+    #[arg(long)]
+    bundle: Option<PathBuf>,
 }
 
 fn default_sdk_out(foundation_out: &Path) -> PathBuf {
@@ -66,9 +73,13 @@ fn main() -> Result<()> {
         ontology.individual_count()
     );
 
-    let sdk_out = args.sdk_out.unwrap_or_else(|| default_sdk_out(&args.out));
-
-    let report = uor_codegen::generate(ontology, &args.out, &sdk_out)?;
+    let report = match &args.bundle {
+        Some(bundle) => uor_codegen::generate_bundled(ontology, &args.out, bundle)?,
+        None => {
+            let sdk_out = args.sdk_out.unwrap_or_else(|| default_sdk_out(&args.out));
+            uor_codegen::generate(ontology, &args.out, &sdk_out)?
+        }
+    };
 
     println!(
         "Generated {} traits, {} methods, {} enums, {} constants",
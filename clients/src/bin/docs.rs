@@ -5,11 +5,12 @@
 //! - `<out>/namespaces/*.html` — Per-namespace reference pages (auto-generated)
 //! - `<out>/concepts/*.html` — Concept explanation pages
 //! - `<out>/guides/*.html` — How-to guide pages
+//! - `<out>/namespaces/*.fragment.html` — Embeddable namespace content (with `--fragments`)
 //! - `<repo-root>/README.md` — Machine-generated repository README
 //!
 //! **Usage:**
 //! ```
-//! uor-docs [--out <path>] [--readme <path>]
+//! uor-docs [--out <path>] [--readme <path>] [--fragments]
 //! ```
 
 #![deny(
@@ -24,7 +25,7 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use clap::Parser;
-use uor_docs::generate;
+use uor_docs::{generate, generate_fragments};
 
 /// Generate UOR Foundation documentation artifacts.
 #[derive(Parser)]
@@ -40,12 +41,19 @@ struct Args {
     /// Path to write the machine-generated README.md (default: repo root).
     #[arg(long, default_value = "README.md")]
     readme: PathBuf,
+
+    /// Also write content-only namespace fragments for embedding elsewhere.
+    #[arg(long)]
+    fragments: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
     generate(&args.out, &args.readme)?;
+    if args.fragments {
+        generate_fragments(&args.out)?;
+    }
 
     println!("Documentation generated successfully.");
     println!("  Docs: {}", args.out.display());
@@ -47,6 +47,21 @@ impl RustFile {
         }
     }
 
+    /// Appends an outer attribute line: `attribute("must_use")` writes `#[must_use]`.
+    pub fn attribute(&mut self, attr: &str) {
+        let _ = writeln!(self.buf, "#[{attr}]");
+    }
+
+    /// Appends one `use` line per path, sorted and de-duplicated.
+    pub fn use_group(&mut self, items: &[&str]) {
+        let mut sorted: Vec<&str> = items.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        for item in sorted {
+            let _ = writeln!(self.buf, "use {item};");
+        }
+    }
+
     /// Appends a module-level doc comment line.
     pub fn module_doc(&mut self, comment: &str) {
         let _ = writeln!(self.buf, "//! {comment}");
@@ -139,7 +154,7 @@ pub fn write_file(path: &Path, content: &str) -> Result<()> {
 /// Phase 11c — true iff `path` starts with the `// @codegen-exempt`
 /// banner. Reads only the first ~256 bytes; missing-file / read-error
 /// returns `false` so non-existent paths pass through to the writer.
-fn is_codegen_exempt(path: &Path) -> bool {
+pub(crate) fn is_codegen_exempt(path: &Path) -> bool {
     let body = match std::fs::read_to_string(path) {
         Ok(b) => b,
         Err(_) => return false,
@@ -318,6 +333,24 @@ fn is_generic_body(c: char) -> bool {
 mod tests {
     use super::*;
 
+    fn empty_file() -> RustFile {
+        RustFile { buf: String::new() }
+    }
+
+    #[test]
+    fn attribute_wraps_in_outer_attribute_syntax() {
+        let mut f = empty_file();
+        f.attribute("derive(Debug, Clone)");
+        assert_eq!(f.buf, "#[derive(Debug, Clone)]\n");
+    }
+
+    #[test]
+    fn use_group_sorts_and_dedups() {
+        let mut f = empty_file();
+        f.use_group(&["b::X", "a::Y", "a::Y"]);
+        assert_eq!(f.buf, "use a::Y;\nuse b::X;\n");
+    }
+
     #[test]
     fn normalize_comment_preserves_plain_text() {
         assert_eq!(normalize_comment("hello world"), "hello world");
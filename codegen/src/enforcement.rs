@@ -52,12 +52,15 @@ pub fn generate_enforcement_module(ontology: &Ontology) -> String {
     generate_builders(&mut f);
     generate_minting_session(&mut f, ontology);
     generate_const_ring_eval(&mut f, ontology);
+    generate_critical_identity(&mut f);
+    generate_dihedral_group(&mut f);
 
     // v0.2.2 Phase C.3: Limbs<N> generic kernel for high Witt levels.
     generate_limbs_kernel(&mut f);
 
     // v0.2.1 ergonomics surface generators (parametric — read from ontology)
     generate_ontology_target_trait(&mut f, ontology);
+    generate_has_iri_trait(&mut f);
     // v0.2.2 Phase C.4: MulContext + MultiplicationCertificate evidence.
     // Must run after generate_ontology_target_trait because it extends the
     // MultiplicationCertificate shim.
@@ -3362,6 +3365,171 @@ fn generate_const_ring_eval(f: &mut RustFile, ontology: &Ontology) {
     }
 }
 
+/// Emit `critical_identity_holds` and `verify_critical_identity_all`: a
+/// one-call conformance check of `neg(bnot(x)) = succ(x)` over `Z/256Z`,
+/// evaluated through the W8 `const_ring_eval_unary_w8` helper.
+fn generate_critical_identity(f: &mut RustFile) {
+    f.doc_comment("Whether the critical identity `neg(bnot(x)) = succ(x)` holds at `x`.");
+    f.doc_comment("");
+    f.doc_comment("Evaluated in `Z/256Z` through [`const_ring_eval_unary_w8`], where");
+    f.doc_comment("`neg(x) = 256 - x mod 256` is the additive inverse and `bnot(x)` flips");
+    f.doc_comment("all eight bits.");
+    f.line("#[inline]");
+    f.line("#[must_use]");
+    f.line("pub const fn critical_identity_holds(x: u8) -> bool {");
+    f.line("    let lhs = const_ring_eval_unary_w8(");
+    f.line("        PrimitiveOp::Neg,");
+    f.line("        const_ring_eval_unary_w8(PrimitiveOp::Bnot, x),");
+    f.line("    );");
+    f.line("    lhs == const_ring_eval_unary_w8(PrimitiveOp::Succ, x)");
+    f.line("}");
+    f.blank();
+    f.doc_comment("Whether [`critical_identity_holds`] for all 256 values of `Z/256Z`.");
+    f.line("#[must_use]");
+    f.line("pub const fn verify_critical_identity_all() -> bool {");
+    f.line("    let mut x: u8 = 0;");
+    f.line("    loop {");
+    f.line("        if !critical_identity_holds(x) {");
+    f.line("            return false;");
+    f.line("        }");
+    f.line("        if x == u8::MAX {");
+    f.line("            return true;");
+    f.line("        }");
+    f.line("        x += 1;");
+    f.line("    }");
+    f.line("}");
+    f.blank();
+}
+
+/// Emits `DihedralElement` and `DihedralGroup`: the concrete `op:D2n` at W8,
+/// the group of order 512 generated by `op:neg` and `op:bnot` acting on
+/// `Z/256Z`. Elements act as `x ↦ ±x + rotation`, listed in canonical order
+/// (rotations `succ^k` first, then reflections `succ^k ∘ neg`).
+fn generate_dihedral_group(f: &mut RustFile) {
+    f.doc_comment("An element of `op:D2n` at W8, acting on `Z/256Z` as");
+    f.doc_comment("`x ↦ rotation + x` (a rotation, `succ^rotation`) or");
+    f.doc_comment("`x ↦ rotation - x` (a reflection, `succ^rotation ∘ neg`).");
+    f.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+    f.line("pub struct DihedralElement {");
+    f.line("    rotation: u8,");
+    f.line("    reflection: bool,");
+    f.line("}");
+    f.blank();
+    f.line("impl DihedralElement {");
+    f.indented_doc_comment("The rotation component `k` of `succ^k` or `succ^k ∘ neg`.");
+    f.line("    #[inline]");
+    f.line("    #[must_use]");
+    f.line("    pub const fn rotation(self) -> u8 {");
+    f.line("        self.rotation");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("Whether this element is a reflection (reverses orientation).");
+    f.line("    #[inline]");
+    f.line("    #[must_use]");
+    f.line("    pub const fn is_reflection(self) -> bool {");
+    f.line("        self.reflection");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("Applies this element to `x`, evaluating `neg` through");
+    f.indented_doc_comment("[`const_ring_eval_unary_w8`].");
+    f.line("    #[inline]");
+    f.line("    #[must_use]");
+    f.line("    pub const fn apply(self, x: u8) -> u8 {");
+    f.line("        let x = if self.reflection {");
+    f.line("            const_ring_eval_unary_w8(PrimitiveOp::Neg, x)");
+    f.line("        } else {");
+    f.line("            x");
+    f.line("        };");
+    f.line("        x.wrapping_add(self.rotation)");
+    f.line("    }");
+    f.line("}");
+    f.blank();
+    f.doc_comment("`op:DihedralGroup` at W8: `D_{2^8}` of order 512, generated by the ring");
+    f.doc_comment("reflection `neg` and the hypercube reflection `bnot`.");
+    f.doc_comment("");
+    f.doc_comment("Elements are indexed in canonical order: `succ^k` at index `k`, then");
+    f.doc_comment("`succ^k ∘ neg` at index `256 + k`. [`DihedralGroup::compose`] follows the");
+    f.doc_comment("group law of function composition.");
+    f.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]");
+    f.line("pub struct DihedralGroup;");
+    f.blank();
+    f.line("impl DihedralGroup {");
+    f.indented_doc_comment("Number of elements, `2 · 256`.");
+    f.line("    pub const ORDER: u16 = 512;");
+    f.blank();
+    f.indented_doc_comment("The identity element, `succ^0`.");
+    f.line("    pub const IDENTITY: DihedralElement = DihedralElement {");
+    f.line("        rotation: 0,");
+    f.line("        reflection: false,");
+    f.line("    };");
+    f.blank();
+    f.indented_doc_comment("The element at `index` in canonical order, or `None` when");
+    f.indented_doc_comment("`index >= ORDER`.");
+    f.line("    #[must_use]");
+    f.line("    pub const fn element(index: u16) -> Option<DihedralElement> {");
+    f.line("        if index >= Self::ORDER {");
+    f.line("            return None;");
+    f.line("        }");
+    f.line("        Some(DihedralElement {");
+    f.line("            rotation: (index % 256) as u8,");
+    f.line("            reflection: index >= 256,");
+    f.line("        })");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("The canonical index of `a`; inverse of [`Self::element`].");
+    f.line("    #[must_use]");
+    f.line("    pub const fn index(a: DihedralElement) -> u16 {");
+    f.line("        a.rotation as u16 + if a.reflection { 256 } else { 0 }");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("The group element realised by a unary `op`: `Neg`, `Bnot`, `Succ`");
+    f.indented_doc_comment("or `Pred`. `None` for binary operations.");
+    f.line("    #[must_use]");
+    f.line("    pub const fn from_op(op: PrimitiveOp) -> Option<DihedralElement> {");
+    f.line("        let (rotation, reflection) = match op {");
+    f.line("            PrimitiveOp::Neg => (0, true),");
+    f.line("            PrimitiveOp::Bnot => (u8::MAX, true),");
+    f.line("            PrimitiveOp::Succ => (1, false),");
+    f.line("            PrimitiveOp::Pred => (u8::MAX, false),");
+    f.line("            _ => return None,");
+    f.line("        };");
+    f.line("        Some(DihedralElement {");
+    f.line("            rotation,");
+    f.line("            reflection,");
+    f.line("        })");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("The composition `a ∘ b` (apply `b`, then `a`).");
+    f.line("    #[must_use]");
+    f.line("    pub const fn compose(a: DihedralElement, b: DihedralElement) -> DihedralElement {");
+    f.line("        let shifted = if a.reflection {");
+    f.line("            const_ring_eval_unary_w8(PrimitiveOp::Neg, b.rotation)");
+    f.line("        } else {");
+    f.line("            b.rotation");
+    f.line("        };");
+    f.line("        DihedralElement {");
+    f.line("            rotation: a.rotation.wrapping_add(shifted),");
+    f.line("            reflection: a.reflection != b.reflection,");
+    f.line("        }");
+    f.line("    }");
+    f.blank();
+    f.indented_doc_comment("The inverse of `a`: reflections are involutions, and `succ^k`");
+    f.indented_doc_comment("inverts to `succ^-k`.");
+    f.line("    #[must_use]");
+    f.line("    pub const fn inverse(a: DihedralElement) -> DihedralElement {");
+    f.line("        if a.reflection {");
+    f.line("            a");
+    f.line("        } else {");
+    f.line("            DihedralElement {");
+    f.line("                rotation: const_ring_eval_unary_w8(PrimitiveOp::Neg, a.rotation),");
+    f.line("                reflection: false,");
+    f.line("            }");
+    f.line("        }");
+    f.line("    }");
+    f.line("}");
+    f.blank();
+}
+
 /// Phase L.2 (target §4.5 + §9 criterion 5): emit `const_ring_eval_w{n}`
 /// helpers for every Limbs-backed WittLevel (widths > 128). Each helper is
 /// a `pub const fn` that accepts two `Limbs<N>` operands and a `PrimitiveOp`
@@ -3556,6 +3724,32 @@ fn verify_shim_coverage(label: &str, expected: &[String], shim_names: &[&str]) {
     }
 }
 
+// Sealed `HasIri` — canonical class IRIs, carried by the per-namespace
+// `iri::{Class}` tags that `traits::generate_namespace_module` emits.
+fn generate_has_iri_trait(f: &mut RustFile) {
+    f.doc_comment("Sealed trait carrying the canonical IRI of an ontology class.");
+    f.doc_comment("");
+    f.doc_comment("Implemented by the zero-sized tags in each namespace module's `iri`");
+    f.doc_comment("submodule, e.g. `kernel::schema::iri::Ring`, one per class trait.");
+    f.doc_comment("Downstream crates cannot implement it because the supertrait is private.");
+    f.line("pub trait HasIri: has_iri_sealed::Sealed {");
+    f.indented_doc_comment("The canonical IRI of the ontology class.");
+    f.line("    const IRI: &'static str;");
+    f.indented_doc_comment("Returns this tag's `IRI` constant.");
+    f.line("    #[must_use]");
+    f.line("    fn iri() -> &'static str {");
+    f.line("        Self::IRI");
+    f.line("    }");
+    f.line("}");
+    f.blank();
+    // pub(crate) so the namespace modules can register their `iri` tags.
+    f.line("pub(crate) mod has_iri_sealed {");
+    f.indented_doc_comment("Private supertrait. Not implementable outside this crate.");
+    f.line("    pub trait Sealed {}");
+    f.line("}");
+    f.blank();
+}
+
 // 2.1.a OntologyTarget — sealed marker trait for foundation-produced types.
 //
 // v0.2.1 ships a small set of **shim structs** (named after their ontology
@@ -5,7 +5,7 @@
 
 use std::fmt::Write as FmtWrite;
 
-use uor_ontology::Ontology;
+use uor_ontology::{iris, Ontology};
 
 use crate::emit::{normalize_comment, RustFile};
 use crate::mapping::local_name;
@@ -186,11 +186,11 @@ fn detect_vocabulary_enum(
 ) {
     let comment = ontology.enum_class_comment(class_name).unwrap_or("");
     if let Some(module) = ontology.find_namespace(ns_prefix) {
-        let class_iri_suffix = format!("/{class_name}");
+        let class_iri = iris::iri_for(ns_prefix, class_name);
         let mut variants: Vec<(String, String)> = module
             .individuals
             .iter()
-            .filter(|ind| ind.type_.ends_with(&class_iri_suffix))
+            .filter(|ind| ind.type_ == class_iri)
             .map(|ind| {
                 let name = local_name(ind.id).to_string();
                 let doc = crate::emit::normalize_comment(ind.comment);
@@ -257,10 +257,10 @@ pub fn generate_enums_file(ontology: &Ontology) -> String {
     for e in &enums {
         f.doc_comment(e.comment);
         if e.non_exhaustive {
-            f.line("#[non_exhaustive]");
+            f.attribute("non_exhaustive");
         }
-        f.line("#[repr(u8)]");
-        f.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]");
+        f.attribute("repr(u8)");
+        f.attribute("derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)");
         let _ = writeln!(f.buf, "pub enum {} {{", e.name);
         for (i, (variant, comment)) in e.variants.iter().enumerate() {
             f.indented_doc_comment(comment);
@@ -322,7 +322,7 @@ pub fn generate_enums_file(ontology: &Ontology) -> String {
          assert!(WittLevel::W8 < WittLevel::W32);",
         "rust",
     );
-    f.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]");
+    f.attribute("derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)");
     f.line("pub struct WittLevel {");
     f.indented_doc_comment("The Witt length n in W_n. Maps to `schema:wittLength`.");
     f.line("    witt_length: u32,");
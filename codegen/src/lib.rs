@@ -29,7 +29,7 @@ use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use uor_ontology::model::Space;
 use uor_ontology::{Ontology, Property};
 
@@ -238,6 +238,120 @@ pub fn generate(ontology: &Ontology, out_dir: &Path, sdk_out: &Path) -> Result<G
     Ok(report)
 }
 
+/// Generates the complete `uor-foundation` crate as a single `out_file`.
+///
+/// The multi-file crate is generated into a scratch directory exactly as
+/// [`generate`] would, then every `mod foo;` declaration is replaced by an
+/// inline `mod foo { ... }` block holding that file's contents. The result
+/// is a standalone crate root (it keeps `#![no_std]`) for consumers who
+/// prefer vendoring one file over depending on the crate.
+///
+/// Hand-maintained modules (`// @codegen-exempt`) are read from
+/// `foundation_src`, the existing `foundation/src/`, in preference to the
+/// stubs the generator writes for them.
+///
+/// # Errors
+///
+/// Returns an error if the scratch crate cannot be generated, a declared
+/// module has no source file, or `out_file` cannot be written.
+pub fn generate_bundled(
+    ontology: &Ontology,
+    foundation_src: &Path,
+    out_file: &Path,
+) -> Result<GenerationReport> {
+    static STAGING_SEQ: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let seq = STAGING_SEQ.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let staging = std::env::temp_dir().join(format!(
+        "uor-foundation-bundle-{}-{seq}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&staging);
+
+    let result =
+        generate(ontology, &staging.join("src"), &staging.join("sdk")).and_then(|mut report| {
+            let src = staging.join("src");
+            let bundled = inline_modules(foundation_src, &src, &src, &src.join("lib.rs"))?;
+            emit::write_file(out_file, &bundled)?;
+            report.files = vec![format!("{}", out_file.display())];
+            Ok(report)
+        });
+    let _ = std::fs::remove_dir_all(&staging);
+    result
+}
+
+/// Returns `file`'s source with each `mod foo;` line replaced by an inline
+/// module block, recursively. `dir` is where `foo.rs` / `foo/mod.rs` live;
+/// `root` is the generated `src/` directory and `exempt` the directory
+/// holding the hand-maintained sources.
+fn inline_modules(exempt: &Path, root: &Path, dir: &Path, file: &Path) -> Result<String> {
+    let source = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read: {}", file.display()))?;
+    let mut out = String::with_capacity(source.len());
+    for line in source.lines() {
+        let Some((head, name)) = parse_mod_decl(line) else {
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        };
+        let child_file = resolve_module_file(exempt, root, dir, name)?;
+        let body = inline_modules(exempt, root, &dir.join(name), &child_file)?;
+        let _ = writeln!(out, "{head} {{");
+        out.push_str(&body);
+        out.push_str("}\n");
+    }
+    Ok(out)
+}
+
+/// Splits an out-of-line module declaration (`pub mod foo;`) into its
+/// `pub mod foo` head and the module name.
+fn parse_mod_decl(line: &str) -> Option<(&str, &str)> {
+    let head = line.trim_end().strip_suffix(';')?;
+    let decl = head.trim_start();
+    let name = decl
+        .strip_prefix("pub ")
+        .unwrap_or(decl)
+        .strip_prefix("mod ")?;
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return None;
+    }
+    Some((head, name))
+}
+
+/// Finds the source file for module `name` declared in `dir`. A file at
+/// the same relative path under `exempt` that carries the
+/// `// @codegen-exempt` banner wins over the generated copy, since the
+/// generator's output for it is only a stub; otherwise the generated file
+/// is used, falling back to `exempt` for modules the generator never writes.
+fn resolve_module_file(
+    exempt: &Path,
+    root: &Path,
+    dir: &Path,
+    name: &str,
+) -> Result<std::path::PathBuf> {
+    let rel = dir.strip_prefix(root).unwrap_or(Path::new(""));
+    let candidates = |base: &Path| {
+        [
+            base.join(format!("{name}.rs")),
+            base.join(name).join("mod.rs"),
+        ]
+    };
+    if let Some(hand) = candidates(&exempt.join(rel))
+        .into_iter()
+        .find(|p| p.exists() && emit::is_codegen_exempt(p))
+    {
+        return Ok(hand);
+    }
+    for base in [dir.to_path_buf(), exempt.join(rel)] {
+        if let Some(found) = candidates(&base).into_iter().find(|p| p.exists()) {
+            return Ok(found);
+        }
+    }
+    Err(anyhow::anyhow!(
+        "No source file for module `{name}` in {}",
+        dir.display()
+    ))
+}
+
 /// Walks up from `out_dir` (expected to be `.../foundation/src/`) looking
 /// for the workspace root — the first ancestor directory containing both a
 /// `Cargo.toml` and a `docs/` dir. Returns `None` if no such ancestor
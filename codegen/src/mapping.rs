@@ -366,10 +366,10 @@ pub fn to_trait_name(label: &str) -> String {
     label.to_string()
 }
 
-/// Extracts the local name from a full IRI (after the last `/` or `#`).
+/// Extracts the local name from a full IRI (after whichever of `/` or `#`
+/// appears last), so `http://x/y#Foo` and `http://x/y/Foo` both yield `Foo`.
 pub fn local_name(iri: &str) -> &str {
-    let after_slash = iri.rsplit('/').next().unwrap_or(iri);
-    after_slash.rsplit('#').next().unwrap_or(after_slash)
+    iri.rsplit(['/', '#']).next().unwrap_or(iri)
 }
 
 /// Class local names whose named individuals are emitted as enum variants
@@ -434,4 +434,23 @@ mod tests {
             "string"
         );
     }
+
+    #[test]
+    fn local_name_splits_on_last_separator() {
+        assert_eq!(local_name("http://x/y/Foo"), "Foo");
+        assert_eq!(local_name("urn:x#Foo"), "Foo");
+        assert_eq!(local_name("http://x/y#Foo"), "Foo");
+        assert_eq!(local_name("http://x/y#a/Foo"), "Foo");
+        assert_eq!(local_name("Foo"), "Foo");
+    }
+
+    #[test]
+    fn local_name_unchanged_for_uor_iris() {
+        for module in &uor_ontology::Ontology::full().namespaces {
+            for class in &module.classes {
+                let last_segment = class.id.rsplit('/').next().unwrap_or(class.id);
+                assert_eq!(local_name(class.id), last_segment, "{}", class.id);
+            }
+        }
+    }
 }
@@ -35,6 +35,7 @@
 //! check or resolver is a pure ontology edit.
 
 use crate::emit::RustFile;
+use uor_ontology::iris;
 use uor_ontology::model::{IndividualValue, Ontology};
 
 /// Convert an IRI to its local name.
@@ -2335,7 +2336,7 @@ fn emit_two_sat_decider(f: &mut RustFile, ontology: &Ontology) {
     // v0.2.1 Phase 7b.6: bounds sourced from reduction:TwoSatBound individual.
     let bound = individuals_of_type(ontology, "https://uor.foundation/reduction/SatBound")
         .into_iter()
-        .find(|i| i.id.ends_with("/TwoSatBound"));
+        .find(|i| i.id == iris::iri_for("reduction", "TwoSatBound"));
     let max_vars = bound
         .and_then(|b| ind_prop_int(b, "https://uor.foundation/reduction/maxVarCount"))
         .unwrap_or(256) as usize;
@@ -2531,7 +2532,7 @@ fn emit_horn_sat_decider(f: &mut RustFile, ontology: &Ontology) {
     // v0.2.1 Phase 7b.6: bounds sourced from reduction:HornSatBound individual.
     let bound = individuals_of_type(ontology, "https://uor.foundation/reduction/SatBound")
         .into_iter()
-        .find(|i| i.id.ends_with("/HornSatBound"));
+        .find(|i| i.id == iris::iri_for("reduction", "HornSatBound"));
     let max_vars = bound
         .and_then(|b| ind_prop_int(b, "https://uor.foundation/reduction/maxVarCount"))
         .unwrap_or(256) as usize;
@@ -2648,7 +2649,7 @@ fn emit_preflight_checks(f: &mut RustFile, ontology: &Ontology) {
         .namespaces
         .iter()
         .flat_map(|n| n.individuals.iter())
-        .find(|i| i.id.ends_with("/CompileUnitShape"))
+        .find(|i| i.id == iris::iri_for("conformance", "CompileUnitShape"))
         .map(|i| i.id)
         .unwrap_or("https://uor.foundation/conformance/CompileUnitShape");
     let budget_constraint_iri = ontology
@@ -2674,13 +2675,13 @@ fn emit_preflight_checks(f: &mut RustFile, ontology: &Ontology) {
     let preflight_budget_ns =
         individuals_of_type(ontology, "https://uor.foundation/reduction/TimingBound")
             .into_iter()
-            .find(|i| i.id.ends_with("/PreflightTimingBound"))
+            .find(|i| i.id == iris::iri_for("reduction", "PreflightTimingBound"))
             .and_then(|i| ind_prop_int(i, "https://uor.foundation/reduction/preflightBudgetNs"))
             .unwrap_or(10_000_000);
     let runtime_budget_ns =
         individuals_of_type(ontology, "https://uor.foundation/reduction/TimingBound")
             .into_iter()
-            .find(|i| i.id.ends_with("/RuntimeTimingBound"))
+            .find(|i| i.id == iris::iri_for("reduction", "RuntimeTimingBound"))
             .and_then(|i| ind_prop_int(i, "https://uor.foundation/reduction/runtimeBudgetNs"))
             .unwrap_or(10_000_000);
 
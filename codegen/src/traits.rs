@@ -185,14 +185,15 @@ pub fn generate_namespace_module(
         }
     }
 
-    // Emit imports in alphabetical order (enum imports before HostTypes).
-    enum_imports.sort_unstable();
-    for imp in &enum_imports {
-        let _ = writeln!(f.buf, "use crate::enums::{imp};");
-    }
+    let mut imports: Vec<String> = enum_imports
+        .iter()
+        .map(|imp| format!("crate::enums::{imp}"))
+        .collect();
     if needs_host_types {
-        f.line("use crate::HostTypes;");
+        imports.push("crate::HostTypes".to_string());
     }
+    let imports: Vec<&str> = imports.iter().map(String::as_str).collect();
+    f.use_group(&imports);
     f.blank();
 
     // Build property-to-domain lookup
@@ -213,6 +214,10 @@ pub fn generate_namespace_module(
         );
     }
 
+    // Canonical class IRIs, so implementers can round-trip typed objects
+    // back to the ontology term at runtime.
+    generate_iri_tags(&mut f, module, &skip_classes);
+
     // Phase 2 (orphan-closure): emit Null stubs for every Path-1 class in
     // this namespace. Each stub impls its ontology trait (and every
     // transitive non-Thing supertrait) with absent-sentinel defaults.
@@ -996,6 +1001,49 @@ fn generate_trait(
     f.blank();
 }
 
+/// Emits the `iri` submodule: one zero-sized tag per class trait, each
+/// implementing the sealed `crate::enforcement::HasIri` with the class IRI.
+/// The IRI lives on a tag rather than on the class trait itself so the
+/// traits stay dyn-compatible and `T::iri()` never resolves ambiguously
+/// across a supertrait chain.
+fn generate_iri_tags(f: &mut RustFile, module: &NamespaceModule, skip_classes: &HashSet<&str>) {
+    if module
+        .classes
+        .iter()
+        .all(|c| skip_classes.contains(local_name(c.id)))
+    {
+        return;
+    }
+    f.doc_comment("Zero-sized tags carrying the canonical IRI of each class trait in this");
+    f.doc_comment("namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.");
+    f.line("pub mod iri {");
+    for class in &module.classes {
+        let name = local_name(class.id);
+        if skip_classes.contains(name) {
+            continue;
+        }
+        f.indented_doc_comment(&format!("IRI tag for [`{name}`](super::{name})."));
+        f.line("    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]");
+        let _ = writeln!(f.buf, "    pub struct {name};");
+        let _ = writeln!(
+            f.buf,
+            "    impl crate::enforcement::has_iri_sealed::Sealed for {name} {{}}"
+        );
+        let _ = writeln!(f.buf, "    impl crate::enforcement::HasIri for {name} {{");
+        let one_line = format!("        const IRI: &'static str = \"{}\";", class.id);
+        // Match rustfmt, which breaks after `=` once the line exceeds 100 chars.
+        if one_line.chars().count() <= 100 {
+            f.line(&one_line);
+        } else {
+            f.line("        const IRI: &'static str =");
+            let _ = writeln!(f.buf, "            \"{}\";", class.id);
+        }
+        f.line("    }");
+    }
+    f.line("}");
+    f.blank();
+}
+
 /// Generates a method (and possibly an associated type) for a property.
 fn generate_property_method(
     f: &mut RustFile,
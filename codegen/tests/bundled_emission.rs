@@ -0,0 +1,192 @@
+//! `generate_bundled` emits the whole foundation crate as one file: every
+//! trait the multi-file generator produces is present, the inlined `mod`
+//! blocks leave the source brace-balanced, and the file compiles on its own
+//! against `libm`.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use uor_codegen::{generate, generate_bundled};
+use uor_ontology::Ontology;
+
+fn trait_names(source: &str, names: &mut BTreeSet<String>) {
+    for line in source.lines() {
+        if let Some(rest) = line.trim_start().strip_prefix("pub trait ") {
+            let name: String = rest
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            names.insert(name);
+        }
+    }
+}
+
+fn foundation_src() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../foundation/src")
+}
+
+/// The `libm` version pinned in the workspace `Cargo.lock`.
+fn locked_libm_version() -> Option<String> {
+    let lock = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("../Cargo.lock"))
+        .ok()?;
+    let mut lines = lock.lines();
+    while let Some(line) = lines.next() {
+        if line == "name = \"libm\"" {
+            let version = lines.next()?.strip_prefix("version = \"")?;
+            return Some(version.trim_end_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// The `libm` rlib cargo built for this test binary (a dev-dependency),
+/// found next to the binary in `target/<profile>/deps`. Stale builds of
+/// other versions are skipped by matching the rlib's embedded
+/// `libm-<version>` crate metadata against `Cargo.lock`; among several
+/// matching artifacts the most recently built one wins.
+fn libm_rlib(deps: &Path) -> Option<PathBuf> {
+    let marker = format!("libm-{}", locked_libm_version()?);
+    std::fs::read_dir(deps)
+        .ok()?
+        .flatten()
+        .filter(|e| {
+            e.file_name()
+                .to_str()
+                .is_some_and(|n| n.starts_with("liblibm-") && n.ends_with(".rlib"))
+        })
+        .filter(|e| {
+            std::fs::read(e.path())
+                .is_ok_and(|bytes| bytes.windows(marker.len()).any(|w| w == marker.as_bytes()))
+        })
+        .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok())
+        .map(|e| e.path())
+}
+
+fn collect_rs(dir: &Path, out: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_rs(&path, out);
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            out.push(std::fs::read_to_string(&path).unwrap_or_default());
+        }
+    }
+}
+
+#[test]
+fn bundled_output_covers_multi_file_traits_and_is_balanced() {
+    let ontology = Ontology::full();
+    let scratch = std::env::temp_dir().join(format!("uor-bundle-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&scratch);
+
+    let multi = generate(ontology, &scratch.join("src"), &scratch.join("sdk"));
+    assert!(multi.is_ok(), "multi-file generation failed: {multi:?}");
+    let bundle_path = scratch.join("foundation.rs");
+    let bundled = generate_bundled(ontology, &foundation_src(), &bundle_path);
+    assert!(bundled.is_ok(), "bundled generation failed: {bundled:?}");
+
+    let mut sources = Vec::new();
+    collect_rs(&scratch.join("src"), &mut sources);
+    let bundle = std::fs::read_to_string(&bundle_path).unwrap_or_default();
+    let _ = std::fs::remove_dir_all(&scratch);
+
+    let mut expected = BTreeSet::new();
+    for source in &sources {
+        trait_names(source, &mut expected);
+    }
+    let mut actual = BTreeSet::new();
+    trait_names(&bundle, &mut actual);
+    assert!(
+        !expected.is_empty(),
+        "multi-file generator produced no traits"
+    );
+    let missing: Vec<_> = expected.difference(&actual).collect();
+    assert!(
+        missing.is_empty(),
+        "traits missing from bundle: {missing:?}"
+    );
+
+    assert!(
+        !bundle
+            .lines()
+            .any(|l| l.trim_start().starts_with("pub mod ") && l.ends_with(';')),
+        "bundle still contains out-of-line `mod` declarations"
+    );
+    assert_eq!(
+        bundle.matches('{').count(),
+        bundle.matches('}').count(),
+        "bundle braces are unbalanced"
+    );
+}
+
+#[test]
+fn bundled_output_compiles_with_rustc() {
+    let scratch = std::env::temp_dir().join(format!("uor-bundle-rustc-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&scratch);
+    let bundle_path = scratch.join("foundation.rs");
+    let bundled = generate_bundled(Ontology::full(), &foundation_src(), &bundle_path);
+    assert!(bundled.is_ok(), "bundled generation failed: {bundled:?}");
+
+    let deps = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .unwrap_or_default();
+    let Some(libm) = libm_rlib(&deps) else {
+        let _ = std::fs::remove_dir_all(&scratch);
+        eprintln!(
+            "skipping: no libm rlib matching Cargo.lock in {}",
+            deps.display()
+        );
+        return;
+    };
+    let output = Command::new("rustc")
+        .args(["--edition", "2021", "--crate-type", "lib"])
+        .args(["--crate-name", "uor_foundation", "--emit=metadata"])
+        .arg("-L")
+        .arg(format!("dependency={}", deps.display()))
+        .arg("--extern")
+        .arg(format!("libm={}", libm.display()))
+        .arg("--out-dir")
+        .arg(&scratch)
+        .arg(&bundle_path)
+        .output();
+    let _ = std::fs::remove_dir_all(&scratch);
+    assert!(output.is_ok(), "failed to run rustc: {output:?}");
+    if let Ok(output) = output {
+        assert!(
+            output.status.success(),
+            "bundle does not compile:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn bundled_output_keeps_codegen_exempt_bodies() {
+    let scratch = std::env::temp_dir().join(format!("uor-bundle-exempt-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&scratch);
+    let bundle_path = scratch.join("foundation.rs");
+    let bundled = generate_bundled(Ontology::full(), &foundation_src(), &bundle_path);
+    assert!(bundled.is_ok(), "bundled generation failed: {bundled:?}");
+    let bundle = std::fs::read_to_string(&bundle_path).unwrap_or_default();
+    let _ = std::fs::remove_dir_all(&scratch);
+
+    let br = std::fs::read_to_string(foundation_src().join("primitives/br.rs")).unwrap_or_default();
+    assert!(!br.is_empty(), "foundation/src/primitives/br.rs is empty");
+    // The bundle is rustfmt-ed as a whole, so the inlined body is indented
+    // one level deeper than the source file; compare with indentation
+    // stripped.
+    let unindent = |s: &str| s.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    assert!(
+        unindent(&bundle).contains(&format!("pub mod br {{\n{}\n}}", unindent(&br))),
+        "bundled `primitives::br` differs from foundation/src/primitives/br.rs"
+    );
+    assert!(
+        !bundle.contains("accepts every input unconditionally"),
+        "bundle contains Phase-12 verification stubs"
+    );
+}
@@ -64,3 +64,30 @@ fn witt_level_default_impl_present() {
         "WittLevel::default() must return Self::W8"
     );
 }
+
+#[test]
+fn every_enum_and_witt_level_derive_core_value_traits() {
+    let source = generate_enums_file(Ontology::full());
+    let lines: Vec<&str> = source.lines().collect();
+
+    // Downstream tests rely on `Debug`/`Clone`/`Copy`/`PartialEq`/`Eq` on
+    // every plain enum and on the `WittLevel` unit-like newtype. All five
+    // are `core` derives, so the `#![no_std]` foundation crate is unaffected.
+    for (idx, line) in lines.iter().enumerate() {
+        if !(line.starts_with("pub enum ") || line.starts_with("pub struct WittLevel")) {
+            continue;
+        }
+        let derive = lines[..idx]
+            .iter()
+            .rev()
+            .find(|l| l.starts_with("#[derive("))
+            .copied()
+            .unwrap_or("");
+        for required in ["Debug", "Clone", "Copy", "PartialEq", "Eq"] {
+            assert!(
+                derive.contains(required),
+                "{line}: derive line `{derive}` is missing {required}"
+            );
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Every generated class trait has its canonical IRI on a zero-sized tag in
+//! the namespace's `iri` submodule, implementing the sealed
+//! `enforcement::HasIri`, so downstream implementers can round-trip typed
+//! objects back to ontology IRIs. The class traits themselves carry no
+//! associated const, which would make them non-dyn-compatible.
+
+use std::collections::HashMap;
+
+use uor_codegen::mapping::namespace_mappings;
+use uor_codegen::traits::generate_namespace_module;
+use uor_ontology::model::Property;
+use uor_ontology::Ontology;
+
+fn render_module(ontology: &Ontology, prefix: &str) -> String {
+    let mut all: HashMap<&str, Vec<&Property>> = HashMap::new();
+    for module in &ontology.namespaces {
+        for prop in &module.properties {
+            if let Some(domain) = prop.domain {
+                all.entry(domain).or_default().push(prop);
+            }
+        }
+    }
+    ontology
+        .find_namespace(prefix)
+        .map(|module| generate_namespace_module(module, &namespace_mappings(), &all))
+        .unwrap_or_default()
+}
+
+#[test]
+fn schema_ring_iri_tag_declares_its_iri() {
+    let source = render_module(Ontology::full(), "schema");
+    let tags = source
+        .split("pub mod iri {")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}\n").next())
+        .unwrap_or_default();
+
+    assert!(
+        tags.contains("pub struct Ring;")
+            && tags.contains("impl crate::enforcement::HasIri for Ring {"),
+        "schema::iri is missing the Ring tag:\n{tags}"
+    );
+    assert!(
+        tags.contains("const IRI: &'static str = \"https://uor.foundation/schema/Ring\""),
+        "schema::iri::Ring is missing its IRI constant:\n{tags}"
+    );
+}
+
+#[test]
+fn class_traits_carry_no_associated_iri() {
+    let source = render_module(Ontology::full(), "schema");
+    let ring = source
+        .split("pub trait Ring<H: HostTypes>")
+        .nth(1)
+        .and_then(|rest| rest.split("\n}\n").next())
+        .unwrap_or_default();
+
+    assert!(!ring.is_empty(), "schema::Ring trait not generated");
+    assert!(
+        !ring.contains("const IRI") && !ring.contains("fn iri()"),
+        "schema::Ring declares an IRI item, breaking dyn compatibility:\n{ring}"
+    );
+}
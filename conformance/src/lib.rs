@@ -140,6 +140,8 @@ pub fn run_all(paths: &WorkspacePaths) -> anyhow::Result<ConformanceReport> {
     report.extend(validators::rust::const_ring_eval_coverage::validate(
         &paths.workspace,
     )?);
+    // Critical identity: neg(bnot(x)) = succ(x) over every byte.
+    report.extend(validators::rust::critical_identity::validate());
     // Phase M.3 (target §5): driver must-use discipline.
     report.extend(validators::rust::driver_must_use::validate(
         &paths.workspace,
@@ -388,6 +390,34 @@ mod tests_unit {
         );
     }
 
+    #[test]
+    fn merging_passing_and_failing_reports_fails() {
+        let mut passing = ConformanceReport::new();
+        passing.push(TestResult::pass("shard/a", "ok"));
+        passing.push(TestResult::pass("shard/a", "ok again"));
+        let mut failing = ConformanceReport::new();
+        failing.push(TestResult::fail("shard/b", "broken"));
+        assert!(passing.all_passed());
+
+        let mut merged = ConformanceReport::new();
+        merged.extend(ConformanceReport::from_iter([passing]));
+        merged.extend(failing);
+        assert!(!merged.all_passed());
+        assert_eq!(merged.results.len(), 3);
+        assert_eq!(merged.failure_count(), 1);
+
+        let collected: ConformanceReport = (0..3)
+            .map(|i| {
+                let mut shard = ConformanceReport::new();
+                shard.push(TestResult::pass("shard", format!("{i}")));
+                shard
+            })
+            .collect();
+        assert!(collected.all_passed());
+        assert_eq!(collected.results.len(), 3);
+        assert_eq!(collected.results[2].message, "2");
+    }
+
     #[test]
     fn owl_dl_constraints_pass() {
         let report = validators::ontology::owl::validate();
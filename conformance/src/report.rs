@@ -104,7 +104,11 @@ impl ConformanceReport {
         self.meta_results.push(result);
     }
 
-    /// Extends this report with results from another report.
+    /// Extends this report with results from another report, e.g. one
+    /// produced by another shard or process.
+    ///
+    /// Results keep their order (this report's first, then `other`'s), and
+    /// [`all_passed`](Self::all_passed) reflects the combined set.
     pub fn extend(&mut self, other: ConformanceReport) {
         self.results.extend(other.results);
         self.meta_results.extend(other.meta_results);
@@ -131,3 +135,14 @@ impl Default for ConformanceReport {
         Self::new()
     }
 }
+
+impl FromIterator<ConformanceReport> for ConformanceReport {
+    /// Merges many reports, in iteration order, into one.
+    fn from_iter<I: IntoIterator<Item = ConformanceReport>>(reports: I) -> Self {
+        let mut combined = Self::new();
+        for report in reports {
+            combined.extend(report);
+        }
+        combined
+    }
+}
@@ -131,7 +131,7 @@ fn validate_trait_completeness(
 
     for module in &ontology.namespaces {
         for class in &module.classes {
-            let local = uor_codegen::mapping::local_name(class.id);
+            let local = class.local_name();
 
             // Skip enum classes
             if enum_classes.contains(&local) {
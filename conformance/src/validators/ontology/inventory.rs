@@ -267,21 +267,15 @@ fn validate_space_classification(report: &mut ConformanceReport) {
     let validator = "ontology/inventory/space_classification";
 
     let kernel: Vec<_> = ontology
-        .namespaces
-        .iter()
-        .filter(|m| m.namespace.space == Space::Kernel)
+        .namespaces_in_space(Space::Kernel)
         .map(|m| m.namespace.prefix)
         .collect();
     let bridge: Vec<_> = ontology
-        .namespaces
-        .iter()
-        .filter(|m| m.namespace.space == Space::Bridge)
+        .namespaces_in_space(Space::Bridge)
         .map(|m| m.namespace.prefix)
         .collect();
     let user: Vec<_> = ontology
-        .namespaces
-        .iter()
-        .filter(|m| m.namespace.space == Space::User)
+        .namespaces_in_space(Space::User)
         .map(|m| m.namespace.prefix)
         .collect();
 
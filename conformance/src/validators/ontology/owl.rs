@@ -2,173 +2,207 @@
 //!
 //! Validates OWL 2 DL constraints on the UOR Foundation ontology:
 //! - Subclass targets must be known classes
+//! - Disjoint-with targets must be known classes, and no class may inherit
+//!   from two classes declared disjoint
+//! - Functional properties have at most one value per individual
 //! - Domain/range targets must be known classes or datatypes
-//! - Disjoint-with targets must be known classes
 //! - Individual type assertions must reference known classes
 //! - No circular imports between namespaces
+//!
+//! Each constraint is a public `check_*` function over any [`Ontology`], so
+//! downstream tools can run a subset; [`validate`] runs them all against
+//! the live spec ontology.
+
+use std::collections::{HashMap, HashSet};
+
+use uor_ontology::{Ontology, PropertyKind, ReferenceError};
 
 use crate::report::{ConformanceReport, TestResult};
 
 /// Validates OWL 2 DL constraints on the live spec ontology.
-///
-/// # Errors
-///
-/// Returns an error only if an unexpected internal error occurs (never on constraint violations).
 pub fn validate() -> ConformanceReport {
     let mut report = ConformanceReport::new();
-    let ontology = uor_ontology::Ontology::full();
+    let ontology = Ontology::full();
+    let references = ontology.validate_references();
 
-    // Collect all known class IRIs
-    let mut known_classes: std::collections::HashSet<&'static str> =
-        std::collections::HashSet::new();
-    for module in &ontology.namespaces {
-        for class in &module.classes {
-            known_classes.insert(class.id);
+    for results in [
+        subclass_targets(&references),
+        disjoint_classes(ontology, &references),
+        check_functional_properties(ontology),
+        domain_range_declared(&references),
+        individual_types(&references),
+        check_circular_imports(ontology),
+    ] {
+        for result in results {
+            report.push(result);
         }
     }
 
-    // Collect all known property IRIs
-    let mut known_properties: std::collections::HashSet<&'static str> =
-        std::collections::HashSet::new();
-    for module in &ontology.namespaces {
-        for prop in &module.properties {
-            known_properties.insert(prop.id);
-        }
-    }
+    report
+}
 
-    // Collect all known individual IRIs
-    let mut known_individuals: std::collections::HashSet<&'static str> =
-        std::collections::HashSet::new();
-    for module in &ontology.namespaces {
-        for ind in &module.individuals {
-            known_individuals.insert(ind.id);
-        }
-    }
+/// Checks that every `rdfs:subClassOf` target is a known class.
+pub fn check_subclass_targets(ontology: &Ontology) -> Vec<TestResult> {
+    subclass_targets(&ontology.validate_references())
+}
 
-    // Known XSD datatypes and OWL primitives that are valid range/domain targets.
-    // Include both prefixed forms (xsd:) and full IRIs for spec compatibility.
-    let known_datatypes: std::collections::HashSet<&'static str> = [
-        // Prefixed forms
-        "xsd:string",
-        "xsd:integer",
-        "xsd:boolean",
-        "xsd:anyURI",
-        "xsd:nonNegativeInteger",
-        "xsd:positiveInteger",
-        "xsd:dateTimeStamp",
-        "xsd:dateTime",
-        "xsd:float",
-        "xsd:double",
-        "owl:Thing",
-        "owl:Class",
-        "rdf:List",
-        "rdfs:Literal",
-        // Full IRI forms
-        "http://www.w3.org/2001/XMLSchema#string",
-        "http://www.w3.org/2001/XMLSchema#integer",
-        "http://www.w3.org/2001/XMLSchema#boolean",
-        "http://www.w3.org/2001/XMLSchema#anyURI",
-        "http://www.w3.org/2001/XMLSchema#nonNegativeInteger",
-        "http://www.w3.org/2001/XMLSchema#positiveInteger",
-        "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
-        "http://www.w3.org/2001/XMLSchema#dateTime",
-        "http://www.w3.org/2001/XMLSchema#float",
-        "http://www.w3.org/2001/XMLSchema#double",
-        "http://www.w3.org/2001/XMLSchema#decimal",
-        "xsd:decimal",
-        "http://www.w3.org/2001/XMLSchema#hexBinary",
-        "xsd:hexBinary",
-        "http://www.w3.org/2002/07/owl#Thing",
-        "http://www.w3.org/2002/07/owl#Class",
-        "http://www.w3.org/1999/02/22-rdf-syntax-ns#List",
-        "http://www.w3.org/2000/01/rdf-schema#Literal",
-    ]
-    .into();
-
-    let mut violations: Vec<String> = Vec::new();
-
-    // Check subclass targets
-    for module in &ontology.namespaces {
-        for class in &module.classes {
-            for parent in class.subclass_of {
-                if !known_classes.contains(parent) && !known_datatypes.contains(parent) {
-                    violations.push(format!(
-                        "Class {} has unknown subClassOf target: {}",
-                        class.id, parent
-                    ));
-                }
-            }
-            for disjoint in class.disjoint_with {
-                if !known_classes.contains(disjoint) {
-                    violations.push(format!(
-                        "Class {} has unknown disjointWith target: {}",
-                        class.id, disjoint
-                    ));
-                }
+/// [`check_subclass_targets`] over precomputed `references`.
+fn subclass_targets(references: &[ReferenceError]) -> Vec<TestResult> {
+    let violations = unresolved(references, &["subClassOf"])
+        .map(|e| {
+            format!(
+                "Class {} has unknown subClassOf target: {}",
+                e.source, e.target
+            )
+        })
+        .collect();
+    single_result(
+        violations,
+        "All subClassOf targets are known classes",
+        "Unknown subClassOf targets",
+    )
+}
+
+/// Checks that every `owl:disjointWith` target is a known class and that no
+/// class is (transitively) a subclass of two classes declared disjoint —
+/// such a class could have no instances.
+pub fn check_disjoint_classes(ontology: &Ontology) -> Vec<TestResult> {
+    disjoint_classes(ontology, &ontology.validate_references())
+}
+
+/// [`check_disjoint_classes`] over precomputed `references`.
+fn disjoint_classes(ontology: &Ontology, references: &[ReferenceError]) -> Vec<TestResult> {
+    let parents: HashMap<&str, &[&str]> = ontology
+        .namespaces
+        .iter()
+        .flat_map(|m| m.classes.iter())
+        .map(|c| (c.id, c.subclass_of))
+        .collect();
+    let disjoint: HashMap<&str, &[&str]> = ontology
+        .namespaces
+        .iter()
+        .flat_map(|m| m.classes.iter())
+        .map(|c| (c.id, c.disjoint_with))
+        .collect();
+
+    let mut violations: Vec<String> = unresolved(references, &["disjointWith"])
+        .map(|e| {
+            format!(
+                "Class {} has unknown disjointWith target: {}",
+                e.source, e.target
+            )
+        })
+        .collect();
+    for class in ontology.namespaces.iter().flat_map(|m| m.classes.iter()) {
+        // The class itself plus every transitive superclass.
+        let mut ancestors: HashSet<&str> = HashSet::new();
+        let mut stack = vec![class.id];
+        while let Some(iri) = stack.pop() {
+            if ancestors.insert(iri) {
+                stack.extend(parents.get(iri).copied().unwrap_or_default());
             }
         }
-    }
-
-    // Check property domain and range targets
-    for module in &ontology.namespaces {
-        for prop in &module.properties {
-            if let Some(domain) = prop.domain {
-                if !known_classes.contains(domain) && !known_datatypes.contains(domain) {
+        let mut sorted: Vec<&str> = ancestors.iter().copied().collect();
+        sorted.sort_unstable();
+        for a in &sorted {
+            for b in disjoint.get(a).copied().unwrap_or_default() {
+                if ancestors.contains(b) {
                     violations.push(format!(
-                        "Property {} has unknown domain: {}",
-                        prop.id, domain
+                        "Class {} is a subclass of disjoint classes {} and {}",
+                        class.id, a, b
                     ));
                 }
             }
-            if !known_classes.contains(prop.range)
-                && !known_datatypes.contains(prop.range)
-                && !prop.range.is_empty()
-            {
-                violations.push(format!(
-                    "Property {} has unknown range: {}",
-                    prop.id, prop.range
-                ));
-            }
         }
     }
+    single_result(
+        violations,
+        "All disjointWith targets are known and no class inherits from disjoint classes",
+        "owl:disjointWith violations",
+    )
+}
 
-    // Check individual type assertions
-    for module in &ontology.namespaces {
-        for ind in &module.individuals {
-            if !known_classes.contains(ind.type_) && !known_datatypes.contains(ind.type_) {
+/// Checks that no named individual asserts a functional object or datatype
+/// property more than once (`owl:FunctionalProperty` allows at most one
+/// value per subject). Annotation properties are skipped: the serializers
+/// never emit `owl:FunctionalProperty` for them.
+pub fn check_functional_properties(ontology: &Ontology) -> Vec<TestResult> {
+    let functional: HashSet<&str> = ontology
+        .namespaces
+        .iter()
+        .flat_map(|m| m.properties.iter())
+        .filter(|p| p.functional && p.kind != PropertyKind::Annotation)
+        .map(|p| p.id)
+        .collect();
+
+    let mut violations = Vec::new();
+    for ind in ontology
+        .namespaces
+        .iter()
+        .flat_map(|m| m.individuals.iter())
+    {
+        let mut seen: HashSet<&str> = HashSet::new();
+        for (prop, _) in ind.properties {
+            if functional.contains(prop) && !seen.insert(prop) {
                 violations.push(format!(
-                    "Individual {} has unknown type: {}",
-                    ind.id, ind.type_
+                    "Individual {} asserts functional property {} more than once",
+                    ind.id, prop
                 ));
             }
         }
     }
+    single_result(
+        violations,
+        "No individual asserts a functional property more than once",
+        "Functional property violations",
+    )
+}
 
-    if violations.is_empty() {
-        report.push(TestResult::pass(
-            "ontology/owl",
-            "All OWL 2 DL structural constraints satisfied",
-        ));
-    } else {
-        report.push(TestResult::fail_with_details(
-            "ontology/owl",
-            "OWL 2 DL constraint violations detected",
-            violations,
-        ));
-    }
+/// Checks that every property domain and range is a known class or datatype.
+pub fn check_domain_range_declared(ontology: &Ontology) -> Vec<TestResult> {
+    domain_range_declared(&ontology.validate_references())
+}
 
-    // Check for circular imports (simple: namespace A imports B, B imports A)
-    check_circular_imports(ontology, &mut report);
+/// [`check_domain_range_declared`] over precomputed `references`.
+fn domain_range_declared(references: &[ReferenceError]) -> Vec<TestResult> {
+    let violations = unresolved(references, &["domain", "range"])
+        .map(|e| {
+            format!(
+                "Property {} has unknown {}: {}",
+                e.source, e.field, e.target
+            )
+        })
+        .collect();
+    single_result(
+        violations,
+        "All property domains and ranges are declared",
+        "Unknown property domains or ranges",
+    )
+}
 
-    report
+/// Checks that every individual's `rdf:type` is a known class.
+pub fn check_individual_types(ontology: &Ontology) -> Vec<TestResult> {
+    individual_types(&ontology.validate_references())
+}
+
+/// [`check_individual_types`] over precomputed `references`.
+fn individual_types(references: &[ReferenceError]) -> Vec<TestResult> {
+    let violations = unresolved(references, &["type"])
+        .map(|e| format!("Individual {} has unknown type: {}", e.source, e.target))
+        .collect();
+    single_result(
+        violations,
+        "All individual type assertions reference known classes",
+        "Unknown individual types",
+    )
 }
 
 /// Checks for circular imports between namespace modules.
-fn check_circular_imports(ontology: &uor_ontology::Ontology, report: &mut ConformanceReport) {
+pub fn check_circular_imports(ontology: &Ontology) -> Vec<TestResult> {
     let mut circular: Vec<String> = Vec::new();
 
     // Build import map
-    let import_map: std::collections::HashMap<&str, &[&str]> = ontology
+    let import_map: HashMap<&str, &[&str]> = ontology
         .namespaces
         .iter()
         .map(|m| (m.namespace.iri, m.namespace.imports))
@@ -186,16 +220,164 @@ fn check_circular_imports(ontology: &uor_ontology::Ontology, report: &mut Confor
         }
     }
 
-    if circular.is_empty() {
-        report.push(TestResult::pass(
-            "ontology/owl",
-            "No circular imports between namespaces",
-        ));
+    single_result(
+        circular,
+        "No circular imports between namespaces",
+        "Circular imports detected between namespaces",
+    )
+}
+
+/// The references in `references` held in one of `fields`. Known classes
+/// and the external class / datatype allow-lists come from
+/// [`Ontology::validate_references`], so this validator cannot drift from
+/// the spec's own notion of a resolvable term.
+fn unresolved<'a>(
+    references: &'a [ReferenceError],
+    fields: &'a [&str],
+) -> impl Iterator<Item = &'a ReferenceError> + 'a {
+    references.iter().filter(|e| fields.contains(&e.field))
+}
+
+/// Wraps a check's violations in a single `ontology/owl` result.
+fn single_result(violations: Vec<String>, pass: &str, fail: &str) -> Vec<TestResult> {
+    if violations.is_empty() {
+        vec![TestResult::pass("ontology/owl", pass)]
     } else {
-        report.push(TestResult::fail_with_details(
+        vec![TestResult::fail_with_details(
             "ontology/owl",
-            "Circular imports detected between namespaces",
-            circular,
-        ));
+            fail,
+            violations,
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uor_ontology::{Class, Namespace, NamespaceModule, Space};
+
+    fn class(id: &'static str, subclass_of: &'static [&'static str]) -> Class {
+        Class {
+            id,
+            label: "",
+            comment: "",
+            subclass_of,
+            disjoint_with: &[],
+        }
+    }
+
+    #[test]
+    fn every_check_passes_on_full_ontology() {
+        let ontology = Ontology::full();
+        for (name, results) in [
+            ("subclass", check_subclass_targets(ontology)),
+            ("disjoint", check_disjoint_classes(ontology)),
+            ("functional", check_functional_properties(ontology)),
+            ("domain_range", check_domain_range_declared(ontology)),
+            ("individuals", check_individual_types(ontology)),
+            ("imports", check_circular_imports(ontology)),
+        ] {
+            assert!(
+                results.iter().all(|r| !r.is_failure()),
+                "{name}: {results:#?}"
+            );
+        }
+    }
+
+    #[test]
+    fn subclass_targets_follow_spec_allow_list() {
+        let ontology = Ontology {
+            version: "0.0.0",
+            base_iri: "https://example.org/",
+            namespaces: vec![NamespaceModule {
+                namespace: Namespace {
+                    prefix: "t",
+                    iri: "https://example.org/t/",
+                    label: "Test",
+                    comment: "",
+                    space: Space::User,
+                    imports: &[],
+                },
+                classes: vec![
+                    class("https://example.org/t/A", &["owl:Thing"]),
+                    class("https://example.org/t/B", &["xsd:string"]),
+                ],
+                properties: vec![],
+                individuals: vec![],
+            }],
+            annotation_properties: vec![],
+        };
+
+        let results = check_subclass_targets(&ontology);
+        let details: Vec<&String> = results.iter().flat_map(|r| r.details.iter()).collect();
+        assert_eq!(details.len(), 1, "{details:?}");
+        assert!(details[0].contains("https://example.org/t/B"));
+    }
+
+    #[test]
+    fn disjoint_targets_accept_external_classes() {
+        let mut a = class("https://example.org/t/A", &[]);
+        a.disjoint_with = &["owl:Thing", "https://example.org/t/Missing"];
+        let ontology = Ontology {
+            version: "0.0.0",
+            base_iri: "https://example.org/",
+            namespaces: vec![NamespaceModule {
+                namespace: Namespace {
+                    prefix: "t",
+                    iri: "https://example.org/t/",
+                    label: "Test",
+                    comment: "",
+                    space: Space::User,
+                    imports: &[],
+                },
+                classes: vec![a],
+                properties: vec![],
+                individuals: vec![],
+            }],
+            annotation_properties: vec![],
+        };
+
+        let results = check_disjoint_classes(&ontology);
+        let details: Vec<&String> = results.iter().flat_map(|r| r.details.iter()).collect();
+        assert_eq!(details.len(), 1, "{details:?}");
+        assert!(details[0].contains("https://example.org/t/Missing"));
+    }
+
+    #[test]
+    fn overlapping_disjoint_classes_fail() {
+        let mut a = class("https://example.org/t/A", &[]);
+        a.disjoint_with = &["https://example.org/t/B"];
+        let ontology = Ontology {
+            version: "0.0.0",
+            base_iri: "https://example.org/",
+            namespaces: vec![NamespaceModule {
+                namespace: Namespace {
+                    prefix: "t",
+                    iri: "https://example.org/t/",
+                    label: "Test",
+                    comment: "",
+                    space: Space::User,
+                    imports: &[],
+                },
+                classes: vec![
+                    a,
+                    class("https://example.org/t/B", &[]),
+                    class(
+                        "https://example.org/t/C",
+                        &["https://example.org/t/A", "https://example.org/t/B"],
+                    ),
+                ],
+                properties: vec![],
+                individuals: vec![],
+            }],
+            annotation_properties: vec![],
+        };
+
+        let results = check_disjoint_classes(&ontology);
+        assert!(results.iter().any(TestResult::is_failure));
+        assert!(results
+            .iter()
+            .flat_map(|r| r.details.iter())
+            .any(|d| d.contains("https://example.org/t/C")));
     }
 }
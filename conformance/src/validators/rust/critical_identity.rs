@@ -0,0 +1,84 @@
+//! Critical identity check: `neg(bnot(x)) = succ(x)` over every byte.
+//!
+//! The ontology defines `op:succ` as the composition `op:composedOf
+//! (op:neg op:bnot)`. This validator confirms the spec still asserts that
+//! composition and runs `uor-foundation`'s published
+//! `verify_critical_identity_all`, listing every byte for which
+//! `critical_identity_holds` is false.
+
+use uor_foundation::enforcement::{critical_identity_holds, verify_critical_identity_all};
+use uor_ontology::Ontology;
+
+use crate::report::{ConformanceReport, TestResult};
+
+const VALIDATOR: &str = "rust/critical_identity";
+
+const SUCC: &str = "https://uor.foundation/op/succ";
+const COMPOSED_OF: &str = "https://uor.foundation/op/composedOf";
+const NEG: &str = "https://uor.foundation/op/neg";
+const BNOT: &str = "https://uor.foundation/op/bnot";
+
+/// Runs the critical identity check.
+pub fn validate() -> ConformanceReport {
+    let mut report = ConformanceReport::new();
+    let mut failures: Vec<String> = Vec::new();
+
+    let composed = Ontology::full()
+        .find_namespace("op")
+        .and_then(|m| m.individuals.iter().find(|i| i.id == SUCC))
+        .and_then(|i| i.properties.iter().find(|(p, _)| *p == COMPOSED_OF))
+        .map(|(_, v)| v);
+    let composition: Vec<&str> = composed
+        .map(|v| {
+            (0..v.list_len().unwrap_or(0))
+                .filter_map(|i| v.list_get(i))
+                .collect()
+        })
+        .unwrap_or_default();
+    if composition != [NEG, BNOT] {
+        failures.push(format!(
+            "op:succ op:composedOf is {composition:?}, expected [op:neg, op:bnot]"
+        ));
+    }
+
+    if !verify_critical_identity_all() {
+        failures.extend(
+            (0..=u8::MAX)
+                .filter(|&x| !critical_identity_holds(x))
+                .map(|x| format!("neg(bnot({x})) != succ({x})")),
+        );
+    }
+
+    if failures.is_empty() {
+        report.push(TestResult::pass(
+            VALIDATOR,
+            "Critical identity neg(bnot(x)) = succ(x) holds for all 256 bytes, \
+             matching op:succ op:composedOf (op:neg op:bnot)",
+        ));
+    } else {
+        report.push(TestResult::fail_with_details(
+            VALIDATOR,
+            "Critical identity neg(bnot(x)) = succ(x) violated",
+            failures,
+        ));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn critical_identity_passes() {
+        let report = validate();
+        assert_eq!(report.results.len(), 1);
+        assert!(report.all_passed(), "{:?}", report.results);
+    }
+
+    #[test]
+    fn foundation_helpers_agree() {
+        assert!(verify_critical_identity_all());
+        assert!((0..=u8::MAX).all(critical_identity_holds));
+    }
+}
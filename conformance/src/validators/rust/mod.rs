@@ -13,6 +13,7 @@ pub mod calibration_presets_valid;
 pub mod const_fn_frontier;
 pub mod const_ring_eval_coverage;
 pub mod correctness;
+pub mod critical_identity;
 pub mod driver_must_use;
 pub mod driver_shape;
 pub mod ebnf_constraint_decl;
@@ -5,6 +5,7 @@
 //! - Every `<html>` element has a `lang` attribute
 //! - No empty `<a>` elements without `title` or `aria-label`
 //! - Skip-to-content link present
+//! - Every `<th>` in a data table has a `scope` attribute
 
 use std::path::Path;
 
@@ -114,4 +115,74 @@ fn check_accessibility(path: &str, content: &str, issues: &mut Vec<String>) {
             ));
         }
     }
+
+    // WCAG 1.3.1: data table header cells must declare their scope
+    let mut remaining = lower.as_str();
+    let mut table_index = 0usize;
+    while let Some(idx) = remaining.find("<table") {
+        remaining = &remaining[idx + 6..];
+        table_index += 1;
+        let end = remaining.find("</table>").unwrap_or(remaining.len());
+        let missing = unscoped_header_cells(&remaining[..end]);
+        if missing > 0 {
+            issues.push(format!(
+                "{}: table #{} has {} <th> cell(s) without scope attribute (WCAG 1.3.1)",
+                path, table_index, missing
+            ));
+        }
+        remaining = &remaining[end..];
+    }
+}
+
+/// Counts `<th>` cells in a (lowercased) table body that lack a `scope` attribute.
+fn unscoped_header_cells(table: &str) -> usize {
+    let mut count = 0;
+    let mut remaining = table;
+    while let Some(idx) = remaining.find("<th") {
+        remaining = &remaining[idx + 3..];
+        // Skip `<thead>` and any other tag that merely starts with `th`.
+        if !remaining.starts_with(['>', ' ', '\t', '\n']) {
+            continue;
+        }
+        let end = remaining.find('>').unwrap_or(remaining.len());
+        if !remaining[..end].contains("scope=") {
+            count += 1;
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHELL_START: &str = "<html lang=\"en\"><body><a href=\"#main-content\">Skip</a>";
+    const SHELL_END: &str = "</body></html>";
+
+    fn issues_for(table: &str) -> Vec<String> {
+        let page = format!("{SHELL_START}{table}{SHELL_END}");
+        let mut issues = Vec::new();
+        check_accessibility("fixture.html", &page, &mut issues);
+        issues
+    }
+
+    #[test]
+    fn table_without_header_scopes_is_flagged() {
+        let issues = issues_for(
+            "<table><thead><tr><th>Name</th><th class=\"x\">Kind</th></tr></thead>\
+             <tbody><tr><td>a</td><td>b</td></tr></tbody></table>",
+        );
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert!(issues[0].contains("2 <th> cell(s) without scope"));
+    }
+
+    #[test]
+    fn table_with_header_scopes_passes() {
+        let issues = issues_for(
+            "<table><thead><tr><th scope=\"col\">Name</th>\
+             <th scope=\"col\" style=\"text-align: left\">Kind</th></tr></thead>\
+             <tbody><tr><th scope=\"row\">a</th><td>b</td></tr></tbody></table>",
+        );
+        assert!(issues.is_empty(), "{issues:?}");
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Verifies that the website covers all ontology terms:
 //! - Every namespace has a landing page under `public/namespaces/<prefix>/index.html`
+//! - Every class has an `id="class-<local>"` anchor on its namespace page
 //! - `search-index.json` contains all class labels
 //! - `sitemap.xml` is present
 
@@ -21,6 +22,7 @@ pub fn validate(artifacts: &Path) -> Result<ConformanceReport> {
     let mut report = ConformanceReport::new();
 
     check_namespace_pages(artifacts, &mut report);
+    check_class_anchors(artifacts, &mut report)?;
     check_search_index(artifacts, &mut report)?;
     check_sitemap(artifacts, &mut report);
 
@@ -59,6 +61,69 @@ fn check_namespace_pages(artifacts: &Path, report: &mut ConformanceReport) {
     }
 }
 
+/// Checks that every class appears as an anchor on its namespace page.
+///
+/// Namespace pages that do not exist are skipped here; they are already
+/// reported by [`check_namespace_pages`].
+///
+/// # Errors
+///
+/// Returns an error if a namespace page exists but cannot be read.
+fn check_class_anchors(artifacts: &Path, report: &mut ConformanceReport) -> Result<()> {
+    let ontology = uor_ontology::Ontology::full();
+    let mut missing: Vec<String> = Vec::new();
+
+    for module in &ontology.namespaces {
+        let page = artifacts
+            .join("namespaces")
+            .join(module.namespace.prefix)
+            .join("index.html");
+        if !page.exists() {
+            continue;
+        }
+        let html = std::fs::read_to_string(&page)
+            .with_context(|| format!("Failed to read {}", page.display()))?;
+        missing.extend(missing_class_anchors(module, &html));
+    }
+
+    if missing.is_empty() {
+        report.push(TestResult::pass(
+            "website/coverage",
+            format!(
+                "All {} classes anchored on their namespace pages",
+                ontology.class_count()
+            ),
+        ));
+    } else {
+        report.push(TestResult::fail_with_details(
+            "website/coverage",
+            format!(
+                "{} classes missing an anchor on their namespace page",
+                missing.len()
+            ),
+            missing,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the IRIs of classes in `module` with no `id="class-<local>"`
+/// anchor in the rendered namespace page `html`.
+fn missing_class_anchors(module: &uor_ontology::NamespaceModule, html: &str) -> Vec<String> {
+    module
+        .classes
+        .iter()
+        .filter(|class| !html.contains(&format!("id=\"class-{}\"", class.local_name())))
+        .map(|class| {
+            format!(
+                "{} (namespaces/{}/index.html)",
+                class.id, module.namespace.prefix
+            )
+        })
+        .collect()
+}
+
 /// Checks that `search-index.json` contains all class labels.
 ///
 /// # Errors
@@ -128,3 +193,32 @@ fn check_sitemap(artifacts: &Path, report: &mut ConformanceReport) {
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incomplete_namespace_page_names_absent_class() {
+        let ontology = uor_ontology::Ontology::full();
+        let module = ontology.find_namespace("schema");
+        assert!(module.is_some(), "schema namespace missing");
+        let Some(module) = module else { return };
+        let absent = "https://uor.foundation/schema/Ring";
+        let html: String = module
+            .classes
+            .iter()
+            .filter(|c| c.id != absent)
+            .map(|c| {
+                format!(
+                    "<tr id=\"class-{}\"></tr>\n",
+                    c.id.rsplit('/').next().unwrap_or("")
+                )
+            })
+            .collect();
+
+        let missing = missing_class_anchors(module, &html);
+        assert_eq!(missing.len(), 1, "{missing:?}");
+        assert!(missing[0].starts_with(absent));
+    }
+}
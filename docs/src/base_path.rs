@@ -0,0 +1,23 @@
+//! Normalization of the `PUBLIC_BASE_PATH` deployment prefix.
+//!
+//! Shared by `uor-docs` and `uor-website` so both generators agree on the
+//! form of the prefix prepended to every absolute link.
+
+/// Normalizes a raw base path: a non-empty value gains a leading `/` and
+/// loses any trailing `/`; an empty value (or a bare `/`) stays empty.
+///
+/// `"/UOR-Framework/"` and `"UOR-Framework"` both become `"/UOR-Framework"`.
+pub fn normalize(raw: &str) -> String {
+    let trimmed = raw.trim().trim_matches('/');
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("/{trimmed}")
+    }
+}
+
+/// Reads `PUBLIC_BASE_PATH` from the environment (default: `""`) and
+/// [`normalize`]s it.
+pub fn from_env() -> String {
+    normalize(&std::env::var("PUBLIC_BASE_PATH").unwrap_or_default())
+}
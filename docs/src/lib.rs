@@ -42,6 +42,7 @@
     clippy::missing_errors_doc
 )]
 
+pub mod base_path;
 pub mod extractor;
 pub mod linker;
 pub mod nav;
@@ -54,8 +55,8 @@ use std::path::Path;
 use anyhow::Result;
 
 use extractor::OntologyIndex;
-use renderer::{escape_html, render_docs_page};
-use uor_ontology::{Individual, IndividualValue, NamespaceModule, Ontology, PropertyKind};
+use renderer::{escape_html, render_docs_page, Breadcrumb};
+use uor_ontology::{Individual, IndividualValue, NamespaceModule, Ontology, PropertyKind, Space};
 
 /// Generates all documentation artifacts.
 ///
@@ -67,8 +68,8 @@ use uor_ontology::{Individual, IndividualValue, NamespaceModule, Ontology, Prope
 pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
     let index = OntologyIndex::from_spec();
 
-    let base_path = std::env::var("PUBLIC_BASE_PATH").unwrap_or_default();
-    let base_path = base_path.trim_end_matches('/');
+    let base_path = base_path::from_env();
+    let base_path = base_path.as_str();
 
     // Verify prose content references (if content/ dir exists alongside this crate)
     let content_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
@@ -96,6 +97,7 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
     generate_content_pages(
         &content_dir.join("concepts"),
         &out_dir.join("concepts"),
+        "/docs/concepts",
         &index,
         &site_nav_html,
         &docs_nav_html,
@@ -141,10 +143,9 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
 </ul>"#,
         &site_nav_html,
         &docs_nav_html,
-        &format!(
-            r#"<a href="{base_path}/">Home</a> › <a href="{base_path}/docs/index.html">Docs</a> › Concepts"#
-        ),
+        &docs_breadcrumb(base_path, "Concepts"),
         base_path,
+        "/docs/concepts/index.html",
     );
     writer::write_html(
         &out_dir.join("concepts").join("index.html"),
@@ -155,6 +156,7 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
     generate_content_pages(
         &content_dir.join("guides"),
         &out_dir.join("guides"),
+        "/docs/guides",
         &index,
         &site_nav_html,
         &docs_nav_html,
@@ -178,10 +180,9 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
 </ul>"#,
         &site_nav_html,
         &docs_nav_html,
-        &format!(
-            r#"<a href="{base_path}/">Home</a> › <a href="{base_path}/docs/index.html">Docs</a> › Guides"#
-        ),
+        &docs_breadcrumb(base_path, "Guides"),
         base_path,
+        "/docs/guides/index.html",
     );
     writer::write_html(&out_dir.join("guides").join("index.html"), &guides_index)?;
 
@@ -189,6 +190,7 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
     generate_single_page(
         &content_dir.join("overview.md"),
         &out_dir.join("overview.html"),
+        "/docs/overview.html",
         "Overview",
         &index,
         &site_nav_html,
@@ -198,6 +200,7 @@ pub fn generate(out_dir: &Path, readme_path: &Path) -> Result<()> {
     generate_single_page(
         &content_dir.join("architecture.md"),
         &out_dir.join("architecture.html"),
+        "/docs/architecture.html",
         "Architecture",
         &index,
         &site_nav_html,
@@ -297,8 +300,63 @@ fn generate_index_page(
     docs_nav_html: &str,
     base_path: &str,
 ) -> String {
+    let content = render_index_content(index, true);
+
+    render_docs_page(
+        "Documentation Index",
+        &content,
+        site_nav_html,
+        docs_nav_html,
+        &Breadcrumb::new()
+            .push("Home", &format!("{base_path}/"))
+            .push("Documentation", "")
+            .render(),
+        base_path,
+        "/docs/index.html",
+    )
+}
+
+/// Renders the body of the documentation index: the inventory summary and
+/// either one flat namespace table or, when `grouped`, one `<h2>` section
+/// with its own table per [`Space`], showing the
+/// kernel → bridge → user layering.
+pub fn render_index_content(index: &OntologyIndex, grouped: bool) -> String {
+    let mut content = format!(
+        "<h1>UOR Foundation Ontology</h1>\n<p>Version {version} — {ns} namespaces, {classes} classes, {props} properties, {inds} named individuals.</p>\n",
+        version = index.version,
+        ns = index.modules.len(),
+        classes = index.classes.len(),
+        props = index.properties.len(),
+        inds = index.individuals.len(),
+    );
+    if !grouped {
+        content.push_str(&namespace_table(index.modules.iter().copied()));
+        return content;
+    }
+    for (heading, space) in [
+        ("Kernel Space", Space::Kernel),
+        ("Bridge Space", Space::Bridge),
+        ("User Space", Space::User),
+    ] {
+        content.push_str(&format!(
+            "<h2 id=\"space-{}\">{heading}</h2>\n",
+            space.as_str()
+        ));
+        content.push_str(&namespace_table(
+            index
+                .modules
+                .iter()
+                .copied()
+                .filter(|m| m.namespace.space == space),
+        ));
+    }
+    content
+}
+
+/// Renders the namespace inventory table for `modules`.
+fn namespace_table<'a>(modules: impl Iterator<Item = &'a NamespaceModule>) -> String {
     let mut rows = String::new();
-    for module in &index.modules {
+    for module in modules {
         let ns = &module.namespace;
         rows.push_str(&format!(
             "<tr><td><a href=\"namespaces/{prefix}.html\">{prefix}</a></td><td>{label}</td><td>{classes}</td><td>{props}</td><td>{inds}</td><td>{space}</td></tr>\n",
@@ -310,35 +368,40 @@ fn generate_index_page(
             space = format!("{:?}", ns.space).to_lowercase(),
         ));
     }
-
-    let content = format!(
-        r#"<h1>UOR Foundation Ontology</h1>
-<p>Version {version} — {ns} namespaces, {classes} classes, {props} properties, {inds} named individuals.</p>
-<table>
+    format!(
+        r#"<table>
 <thead>
-<tr><th>Prefix</th><th>Label</th><th>Classes</th><th>Properties</th><th>Individuals</th><th>Space</th></tr>
+<tr><th scope="col">Prefix</th><th scope="col">Label</th><th scope="col">Classes</th><th scope="col">Properties</th><th scope="col">Individuals</th><th scope="col">Space</th></tr>
 </thead>
 <tbody>
 {rows}
 </tbody>
-</table>"#,
-        version = index.version,
-        ns = index.modules.len(),
-        classes = index.classes.len(),
-        props = index.properties.len(),
-        inds = index.individuals.len(),
-    );
-
-    render_docs_page(
-        "Documentation Index",
-        &content,
-        site_nav_html,
-        docs_nav_html,
-        &format!(r#"<a href="{base_path}/">Home</a> › Documentation"#),
-        base_path,
+</table>
+"#
     )
 }
 
+/// Writes a content-only HTML fragment for every namespace.
+///
+/// Each `namespaces/<prefix>.fragment.html` holds the output of
+/// [`render_namespace_content`] without the site header, sidebar, or
+/// `<html>` shell, so a single namespace reference can be embedded in
+/// another site.
+///
+/// # Errors
+///
+/// Returns an error if any fragment file cannot be written.
+pub fn generate_fragments(out_dir: &Path) -> Result<()> {
+    let ontology = Ontology::full();
+    for module in &ontology.namespaces {
+        let path = out_dir
+            .join("namespaces")
+            .join(format!("{}.fragment.html", module.namespace.prefix));
+        writer::write_html(&path, &render_namespace_content(module))?;
+    }
+    Ok(())
+}
+
 /// Generates a namespace reference page from the spec (100% auto-generated).
 fn generate_namespace_page(
     module: &NamespaceModule,
@@ -346,6 +409,33 @@ fn generate_namespace_page(
     docs_nav_html: &str,
     base_path: &str,
 ) -> String {
+    let ns = &module.namespace;
+    render_docs_page(
+        ns.label,
+        &render_namespace_content(module),
+        site_nav_html,
+        docs_nav_html,
+        &docs_breadcrumb(base_path, ns.label),
+        base_path,
+        &format!("/docs/namespaces/{}.html", ns.prefix),
+    )
+}
+
+/// Renders the `Home › Docs › {current}` trail used by every page under `/docs/`.
+fn docs_breadcrumb(base_path: &str, current: &str) -> String {
+    Breadcrumb::new()
+        .push("Home", &format!("{base_path}/"))
+        .push("Docs", &format!("{base_path}/docs/index.html"))
+        .push(current, "")
+        .render()
+}
+
+/// Renders the inner content of a namespace reference page.
+///
+/// Contains the metadata block and the class, property, and individual
+/// tables, but no page chrome. Used by both the full docs page and the
+/// embeddable fragment written by [`generate_fragments`].
+pub fn render_namespace_content(module: &NamespaceModule) -> String {
     let ns = &module.namespace;
     let mut content = format!(
         r#"<h1>{label}</h1>
@@ -363,9 +453,11 @@ fn generate_namespace_page(
         comment = escape_html(ns.comment),
     );
 
+    content.push_str(&render_namespace_toc(module));
+
     // Imports
     if !ns.imports.is_empty() {
-        content.push_str("<h2>Imports</h2><ul>\n");
+        content.push_str("<h2 id=\"imports\">Imports</h2><ul>\n");
         for imp in ns.imports {
             content.push_str(&format!("<li><code>{}</code></li>\n", escape_html(imp)));
         }
@@ -374,11 +466,11 @@ fn generate_namespace_page(
 
     // Classes
     if !module.classes.is_empty() {
-        content.push_str("<h2>Classes</h2>\n<table>\n<thead><tr><th>Name</th><th>IRI</th><th>Subclass Of</th><th>Disjoint With</th><th>Comment</th></tr></thead>\n<tbody>\n");
+        content.push_str("<h2 id=\"classes\">Classes</h2>\n<table>\n<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">IRI</th><th scope=\"col\">Subclass Of</th><th scope=\"col\">Disjoint With</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n");
         for class in &module.classes {
             content.push_str(&format!(
                 "<tr id=\"{id_fragment}\"><td>{label}</td><td><code>{id}</code></td><td>{parents}</td><td>{disjoint}</td><td>{comment}</td></tr>\n",
-                id_fragment = escape_html(&linker::fragment_from_iri(class.id)),
+                id_fragment = escape_html(class.local_name()),
                 label = escape_html(class.label),
                 id = escape_html(class.id),
                 parents = class.subclass_of.iter().map(|p| format!("<code>{}</code>", escape_html(p))).collect::<Vec<_>>().join(", "),
@@ -391,7 +483,7 @@ fn generate_namespace_page(
 
     // Properties
     if !module.properties.is_empty() {
-        content.push_str("<h2>Properties</h2>\n<table>\n<thead><tr><th>Name</th><th>Kind</th><th>Functional</th><th>Domain</th><th>Range</th><th>Comment</th></tr></thead>\n<tbody>\n");
+        content.push_str("<h2 id=\"properties\">Properties</h2>\n<table>\n<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">Kind</th><th scope=\"col\">Functional</th><th scope=\"col\">Domain</th><th scope=\"col\">Range</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n");
         for prop in &module.properties {
             let kind = match prop.kind {
                 PropertyKind::Datatype => "Datatype",
@@ -414,7 +506,7 @@ fn generate_namespace_page(
 
     // Named individuals
     if !module.individuals.is_empty() {
-        content.push_str("<h2>Named Individuals</h2>\n<table>\n<thead><tr><th>Name</th><th>Type</th><th>Properties</th><th>Comment</th></tr></thead>\n<tbody>\n");
+        content.push_str("<h2 id=\"individuals\">Named Individuals</h2>\n<table>\n<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">Type</th><th scope=\"col\">Properties</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n");
         for ind in &module.individuals {
             let props_html = format_individual_properties(ind);
             content.push_str(&format!(
@@ -429,17 +521,30 @@ fn generate_namespace_page(
         content.push_str("</tbody>\n</table>\n");
     }
 
-    render_docs_page(
-        ns.label,
-        &content,
-        site_nav_html,
-        docs_nav_html,
-        &format!(
-            r#"<a href="{base_path}/">Home</a> › <a href="{base_path}/docs/index.html">Docs</a> › {}"#,
-            escape_html(ns.label)
-        ),
-        base_path,
-    )
+    content
+}
+
+/// Renders the jump list linking to each non-empty section of a namespace page.
+///
+/// Section headings carry stable `id`s (`imports`, `classes`, `properties`,
+/// `individuals`) so the anchors survive regeneration.
+fn render_namespace_toc(module: &NamespaceModule) -> String {
+    let sections = [
+        ("imports", "Imports", module.namespace.imports.len()),
+        ("classes", "Classes", module.classes.len()),
+        ("properties", "Properties", module.properties.len()),
+        ("individuals", "Named Individuals", module.individuals.len()),
+    ];
+    let mut toc = String::from("<aside class=\"page-toc\" aria-label=\"On this page\">\n<ul>\n");
+    for (id, label, count) in sections {
+        if count > 0 {
+            toc.push_str(&format!(
+                "<li><a href=\"#{id}\">{label}</a> ({count})</li>\n"
+            ));
+        }
+    }
+    toc.push_str("</ul>\n</aside>\n");
+    toc
 }
 
 /// Formats an individual's property values as an HTML list.
@@ -475,7 +580,8 @@ fn format_individual_properties(ind: &Individual) -> String {
     items
 }
 
-/// Generates HTML pages from Markdown files in `src_dir`, writing to `out_dir`.
+/// Generates HTML pages from Markdown files in `src_dir`, writing to `out_dir`
+/// (served at `url_dir`).
 ///
 /// # Errors
 ///
@@ -483,6 +589,7 @@ fn format_individual_properties(ind: &Individual) -> String {
 fn generate_content_pages(
     src_dir: &Path,
     out_dir: &Path,
+    url_dir: &str,
     index: &OntologyIndex,
     site_nav_html: &str,
     docs_nav_html: &str,
@@ -503,6 +610,7 @@ fn generate_content_pages(
             generate_single_page(
                 &path,
                 &out_path,
+                &format!("{url_dir}/{stem}.html"),
                 stem,
                 index,
                 site_nav_html,
@@ -520,9 +628,11 @@ fn generate_content_pages(
 /// # Errors
 ///
 /// Returns an error if the source file cannot be read or the output cannot be written.
+#[allow(clippy::too_many_arguments)]
 fn generate_single_page(
     src: &Path,
     out: &Path,
+    page_path: &str,
     title: &str,
     index: &OntologyIndex,
     site_nav_html: &str,
@@ -538,11 +648,9 @@ fn generate_single_page(
         &content_html,
         site_nav_html,
         docs_nav_html,
-        &format!(
-            r#"<a href="{base_path}/">Home</a> › <a href="{base_path}/docs/index.html">Docs</a> › {}"#,
-            escape_html(title)
-        ),
+        &docs_breadcrumb(base_path, title),
         base_path,
+        page_path,
     );
 
     writer::write_html(out, &page)
@@ -658,6 +766,17 @@ mod tests {
         assert!(!index.is_class("https://uor.foundation/nonexistent/Foo"));
     }
 
+    #[test]
+    fn fragment_from_iri_handles_slash_and_hash_iris() {
+        assert_eq!(
+            linker::fragment_from_iri("https://uor.foundation/schema/Ring"),
+            "Ring"
+        );
+        assert_eq!(linker::fragment_from_iri("urn:x#Foo"), "Foo");
+        assert_eq!(linker::fragment_from_iri("http://x/y#Foo"), "Foo");
+        assert_eq!(linker::fragment_from_iri("http://x/y#"), "y");
+    }
+
     #[test]
     fn directive_expansion_known_class() {
         let index = OntologyIndex::from_spec();
@@ -679,6 +798,207 @@ mod tests {
         assert!(expanded.contains('`'));
     }
 
+    #[test]
+    fn namespace_fragment_has_no_page_chrome() {
+        let ontology = Ontology::full();
+        let schema = ontology.find_namespace("schema");
+        assert!(schema.is_some());
+        let fragment = schema.map(render_namespace_content).unwrap_or_default();
+        assert!(!fragment.contains("<html"));
+        assert!(!fragment.contains("<nav"));
+        assert!(fragment.contains("<h2 id=\"classes\">Classes</h2>\n<table>"));
+        assert!(fragment.contains("<tr id=\"Ring\">"));
+    }
+
+    #[test]
+    fn namespace_page_has_toc_anchors() {
+        let ontology = Ontology::full();
+        let schema = ontology.find_namespace("schema");
+        assert!(schema.is_some());
+        let html = schema
+            .map(|m| generate_namespace_page(m, "", "", ""))
+            .unwrap_or_default();
+        assert!(html.contains("<a href=\"#classes\">"));
+        assert!(html.contains("<h2 id=\"classes\">"));
+        assert!(html.contains("<a href=\"#properties\">"));
+        assert!(html.contains("<h2 id=\"properties\">"));
+    }
+
+    #[test]
+    fn writer_rejects_unclosed_div_and_writes_valid_page() {
+        let dir = std::env::temp_dir().join(format!("uor-docs-writer-{}", std::process::id()));
+        let bad = dir.join("bad.html");
+        let good = dir.join("good.html");
+
+        let strict = writer::WriterConfig { validate: true };
+        let err = writer::write_html_with(&bad, "<main><div><p>Ring</p></main>", &strict);
+        assert!(err.is_err(), "unclosed <div> must be rejected");
+        assert!(!bad.exists(), "invalid page must not be written");
+
+        let page =
+            "<main><div><p>Ring &amp; Co &#x2192; <a href=\"a?x=1&amp;y=2\">x</a></p></div></main>";
+        assert!(writer::write_html_with(&good, page, &strict).is_ok());
+        assert!(good.exists());
+        assert!(writer::write_html_with(&bad, "<p>R & D</p>", &strict).is_err());
+
+        let script = "<div><SCRIPT>if (a < b && c) {}</Script></div>";
+        assert!(writer::write_html_with(&good, script, &strict).is_ok());
+
+        assert!(!writer::WriterConfig::default().validate);
+        assert!(writer::write_html(&bad, "<div>&").is_ok());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn namespace_page_marks_namespaces_group_on_active_trail() {
+        let html = nav::render_nav_bootstrap(&nav::build_nav(""), "/namespaces/schema/");
+        assert!(html.contains(
+            "<li class=\"dropend active-trail\"><a class=\"dropdown-item dropdown-toggle\" href=\"/namespaces/\""
+        ));
+        assert!(html.contains("<li class=\"nav-item dropdown active-trail\"><a class=\"nav-link dropdown-toggle\" href=\"#\""));
+        assert!(html.contains("<li class=\"nav-item dropdown\"><a class=\"nav-link dropdown-toggle\" href=\"/learn/\""));
+        assert!(!nav::render_nav_bootstrap(&nav::build_nav(""), "/about/").contains("active-trail"));
+    }
+
+    #[test]
+    fn content_report_collects_every_dangling_directive() {
+        let dir = std::env::temp_dir().join(format!("uor-docs-verify-{}", std::process::id()));
+        let _ = std::fs::create_dir_all(dir.join("concepts"));
+        let _ = std::fs::write(
+            dir.join("a.md"),
+            "See {@class https://uor.foundation/schema/Ring} and {@class https://uor.foundation/schema/NoSuchClass}.\n",
+        );
+        let _ = std::fs::write(
+            dir.join("concepts").join("b.md"),
+            "Uses {@prop https://uor.foundation/op/noSuchProperty}.\n",
+        );
+
+        let report = verifier::verify_content_report(&dir);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(report.issues.len(), 2, "report: {report}");
+        assert!(report.issues[0].path.ends_with("a.md"));
+        assert!(report.issues[0].offset > 0);
+        assert!(report.issues[0].message.contains("schema/NoSuchClass"));
+        assert!(report.issues[1].path.ends_with("concepts/b.md"));
+        assert!(report.issues[1].message.contains("op/noSuchProperty"));
+    }
+
+    #[test]
+    fn docs_pages_link_their_canonical_url() {
+        let index = OntologyIndex::from_spec();
+        let schema = index
+            .modules
+            .iter()
+            .find(|m| m.namespace.prefix == "schema");
+        assert!(schema.is_some());
+        let html = schema
+            .map(|m| generate_namespace_page(m, "", "", "/UOR-Framework"))
+            .unwrap_or_default();
+        assert!(html.contains(
+            "<link rel=\"canonical\" href=\"https://uor.foundation/docs/namespaces/schema.html\">"
+        ));
+        assert_eq!(renderer::canonical_link(""), "");
+    }
+
+    #[test]
+    fn grouped_index_has_a_section_per_space() {
+        let index = OntologyIndex::from_spec();
+        let grouped = render_index_content(&index, true);
+        assert_eq!(grouped.matches("<h2").count(), 3);
+        assert_eq!(grouped.matches("<table>").count(), 3);
+        let kernel = grouped
+            .split("<h2 id=\"space-kernel\">")
+            .nth(1)
+            .and_then(|rest| rest.split("<h2").next())
+            .unwrap_or_default();
+        assert!(kernel.contains("<a href=\"namespaces/schema.html\">schema</a>"));
+
+        let flat = render_index_content(&index, false);
+        assert_eq!(flat.matches("<h2").count(), 0);
+        assert_eq!(flat.matches("<tr><td>").count(), index.modules.len());
+
+        let mut partial = OntologyIndex::from_spec();
+        partial.modules.retain(|m| m.namespace.prefix == "schema");
+        let grouped = render_index_content(&partial, true);
+        assert_eq!(grouped.matches("<tr><td>").count(), 1);
+    }
+
+    #[test]
+    fn base_path_normalizes_slashes() {
+        assert_eq!(base_path::normalize("/UOR-Framework/"), "/UOR-Framework");
+        assert_eq!(base_path::normalize("UOR-Framework"), "/UOR-Framework");
+        assert_eq!(base_path::normalize(""), "");
+    }
+
+    #[test]
+    fn three_level_breadcrumb_links_all_but_last() {
+        let html = Breadcrumb::new()
+            .push("Home", "/")
+            .push("Docs", "/docs/index.html")
+            .push("Ring & Co", "")
+            .render();
+        assert_eq!(
+            html,
+            r#"<a href="/">Home</a> › <a href="/docs/index.html">Docs</a> › Ring &amp; Co"#
+        );
+        assert_eq!(html.matches("<a ").count(), 2);
+    }
+
+    #[test]
+    fn escape_html_into_appends_to_existing_buffer() {
+        let mut out = String::from("<p>");
+        renderer::escape_html_into(r#"a < b && c > "d""#, &mut out);
+        assert_eq!(out, "<p>a &lt; b &amp;&amp; c &gt; &quot;d&quot;");
+        assert_eq!(renderer::escape_html("&lt;"), "&amp;lt;");
+    }
+
+    #[test]
+    fn fenced_code_block_gets_language_class() {
+        let index = OntologyIndex::from_spec();
+        let src = "Example:\n\n```rust\nlet x = a < b;\n```\n";
+        let html = renderer::render_markdown(src, &index);
+        assert!(
+            html.contains("<pre><code class=\"language-rust\">"),
+            "Expected language-rust code block in: {}",
+            html
+        );
+        assert!(html.contains("a &lt; b"));
+    }
+
+    #[test]
+    fn directives_inside_fences_stay_literal() {
+        let index = OntologyIndex::from_spec();
+        let src = "See {@class https://uor.foundation/schema/Ring}.\n\n```text\n{@class https://uor.foundation/schema/Ring}\n```\n";
+        let html = renderer::render_markdown(src, &index);
+        assert!(html.contains(">Ring</a>"), "Prose directive should expand");
+        assert!(
+            html.contains("{@class https://uor.foundation/schema/Ring}"),
+            "Fenced directive should remain literal in: {}",
+            html
+        );
+    }
+
+    #[test]
+    fn checked_expansion_reports_dangling_iri() {
+        let index = OntologyIndex::from_spec();
+        let src = "Known {@class https://uor.foundation/schema/Ring}, typo {@class https://uor.foundation/schema/Rnig}.";
+        let (expanded, warnings) = renderer::expand_directives_checked(src, &index);
+        assert!(expanded.contains("[Ring]"));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, "class");
+        assert_eq!(warnings[0].iri, "https://uor.foundation/schema/Rnig");
+        assert_eq!(&src[warnings[0].offset..warnings[0].offset + 2], "{@");
+    }
+
+    #[test]
+    fn shipped_content_has_no_dangling_directives() {
+        let content_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("content");
+        let result = verifier::verify_content(&content_dir);
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
     #[test]
     fn directive_expansion_count() {
         let index = OntologyIndex::from_spec();
@@ -691,4 +1011,16 @@ mod tests {
             expanded
         );
     }
+
+    #[test]
+    fn markdown_table_headers_carry_scope() {
+        let html = renderer::markdown_to_html("| a | b |\n|---|--:|\n| 1 | 2 |\n");
+        assert!(html.contains("<th scope=\"col\">a</th>"), "{html}");
+        assert!(
+            html.contains("<th scope=\"col\" style=\"text-align: right\">b</th>"),
+            "{html}"
+        );
+        assert!(html.contains("<td>1</td>"), "{html}");
+        assert!(!html.contains("<th>"), "{html}");
+    }
 }
@@ -19,16 +19,17 @@ pub fn resolve_ref(kind: &str, iri: &str, index: &OntologyIndex) -> String {
     let fragment = fragment_from_iri(iri);
 
     let _ = kind; // kind used for semantic clarity but path is same for all
-    let base_path = std::env::var("PUBLIC_BASE_PATH").unwrap_or_default();
-    let base_path = base_path.trim_end_matches('/');
-    format!("{}/docs/namespaces/{}.html#{}", base_path, prefix, fragment)
+    format!(
+        "{}/docs/namespaces/{}.html#{}",
+        crate::base_path::from_env(),
+        prefix,
+        fragment
+    )
 }
 
-/// Extracts the local name from an IRI (the last path segment after `/`).
+/// Extracts the local name from an IRI: the text after whichever of `/` or
+/// `#` appears last, ignoring a trailing `#`.
 pub fn fragment_from_iri(iri: &str) -> String {
-    iri.rsplit('/')
-        .next()
-        .unwrap_or(iri)
-        .trim_end_matches('#')
-        .to_string()
+    let iri = iri.trim_end_matches('#');
+    iri.rsplit(['/', '#']).next().unwrap_or(iri).to_string()
 }
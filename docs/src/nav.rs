@@ -159,11 +159,7 @@ fn build_namespace_nav(ontology: &Ontology, base_path: &str) -> Vec<NavItem> {
             children: Vec::new(),
         });
 
-        for m in ontology
-            .namespaces
-            .iter()
-            .filter(|m| &m.namespace.space == space)
-        {
+        for m in ontology.namespaces_in_space(*space) {
             children.push(NavItem {
                 label: m.namespace.label.to_string(),
                 url: format!("{base_path}/namespaces/{}/", m.namespace.prefix),
@@ -198,9 +194,17 @@ fn render_bs_item(
     root_url: &str,
     depth: usize,
 ) {
-    let is_current = !item.url.is_empty()
-        && (current_path == item.url
-            || (item.url != root_url && current_path.starts_with(&item.url)));
+    let is_current = is_current(item, current_path, root_url);
+    // Dropdowns whose subtree holds the current page sit on the active trail.
+    let trail = if item
+        .children
+        .iter()
+        .any(|child| contains_current(child, current_path, root_url))
+    {
+        " active-trail"
+    } else {
+        ""
+    };
     let indent = "  ".repeat(depth);
 
     if item.url.is_empty() && item.children.is_empty() {
@@ -252,7 +256,7 @@ fn render_bs_item(
                 )
             };
             html.push_str(&format!(
-                "{indent}<li class=\"nav-item dropdown\">{toggle}\n\
+                "{indent}<li class=\"nav-item dropdown{trail}\">{toggle}\n\
                  {indent}<ul class=\"dropdown-menu\">\n"
             ));
             for child in &item.children {
@@ -271,7 +275,7 @@ fn render_bs_item(
                 escape_html(&item.label)
             );
             html.push_str(&format!(
-                "{indent}<li class=\"dropend\">{toggle}\n\
+                "{indent}<li class=\"dropend{trail}\">{toggle}\n\
                  {indent}<ul class=\"dropdown-menu\">\n"
             ));
             for child in &item.children {
@@ -281,3 +285,21 @@ fn render_bs_item(
         }
     }
 }
+
+/// Whether `item` links to the page at `current_path` (or a page beneath it).
+///
+/// The root link only matches exactly, since every path starts with it.
+fn is_current(item: &NavItem, current_path: &str, root_url: &str) -> bool {
+    !item.url.is_empty()
+        && (current_path == item.url
+            || (item.url != root_url && current_path.starts_with(&item.url)))
+}
+
+/// Whether `item` or any of its descendants is the current page.
+fn contains_current(item: &NavItem, current_path: &str, root_url: &str) -> bool {
+    is_current(item, current_path, root_url)
+        || item
+            .children
+            .iter()
+            .any(|child| contains_current(child, current_path, root_url))
+}
@@ -1,34 +1,135 @@
 //! Renders Markdown content with `{@class}`, `{@prop}`, `{@ind}` DSL expansion.
 
-use pulldown_cmark::{html, Options, Parser};
+use pulldown_cmark::{html, Alignment, CowStr, Event, Options, Parser, Tag, TagEnd};
 
 use crate::extractor::OntologyIndex;
 use crate::linker::resolve_ref;
 
 /// Renders a Markdown file to HTML, expanding `{@class}`, `{@prop}`, `{@ind}` directives.
 ///
+/// Fenced code blocks (```` ```rust ```` / `~~~`) are passed through verbatim:
+/// directives inside them are not expanded, and pulldown-cmark emits them as
+/// `<pre><code class="language-…">` with HTML-escaped contents so a
+/// client-side highlighter can pick them up.
+///
 /// # Errors
 ///
 /// This function is infallible (returns `String`).
 pub fn render_markdown(source: &str, index: &OntologyIndex) -> String {
-    let expanded = expand_directives(source, index);
+    let expanded = expand_directives_outside_fences(source, index);
     markdown_to_html(&expanded)
 }
 
+/// Expands directives in prose while leaving fenced code blocks untouched.
+fn expand_directives_outside_fences(source: &str, index: &OntologyIndex) -> String {
+    expand_outside_fences_checked(source, index).0
+}
+
+/// Checks every directive outside fenced code blocks and returns one warning
+/// per directive that does not resolve. Offsets are relative to `source`.
+///
+/// This is the same scan `render_markdown` performs, so a clean result means
+/// the rendered page contains no fallback code spans.
+pub fn check_directives(source: &str, index: &OntologyIndex) -> Vec<DirectiveWarning> {
+    expand_outside_fences_checked(source, index).1
+}
+
+/// Fence-aware wrapper around [`expand_directives_checked`].
+fn expand_outside_fences_checked(
+    source: &str,
+    index: &OntologyIndex,
+) -> (String, Vec<DirectiveWarning>) {
+    let mut result = String::with_capacity(source.len());
+    let mut warnings = Vec::new();
+    let mut prose_start = 0;
+    let mut offset = 0;
+    let mut open_fence: Option<(char, usize)> = None;
+
+    let mut flush_prose = |start: usize, end: usize, result: &mut String| {
+        let (expanded, found) = expand_directives_checked(&source[start..end], index);
+        result.push_str(&expanded);
+        warnings.extend(found.into_iter().map(|mut w| {
+            w.offset += start;
+            w
+        }));
+    };
+
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        match open_fence {
+            None => {
+                if let Some(fence) = fence_marker(trimmed) {
+                    flush_prose(prose_start, offset, &mut result);
+                    result.push_str(line);
+                    open_fence = Some(fence);
+                }
+            }
+            Some((ch, len)) => {
+                result.push_str(line);
+                if fence_marker(trimmed).is_some_and(|(c, n)| {
+                    c == ch && n >= len && trimmed.trim_start_matches(c).trim().is_empty()
+                }) {
+                    open_fence = None;
+                    prose_start = offset + line.len();
+                }
+            }
+        }
+        offset += line.len();
+    }
+
+    if open_fence.is_none() {
+        flush_prose(prose_start, source.len(), &mut result);
+    }
+    (result, warnings)
+}
+
+/// Returns the fence character and run length if `line` opens or closes a
+/// fenced code block (three or more backticks or tildes).
+fn fence_marker(line: &str) -> Option<(char, usize)> {
+    let ch = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.chars().take_while(|c| *c == ch).count();
+    (len >= 3).then_some((ch, len))
+}
+
+/// A directive that could not be resolved against the ontology.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectiveWarning {
+    /// Directive kind (`class`, `prop`, `ind`, `count`, or the unrecognised keyword).
+    pub kind: String,
+    /// The IRI (or count key) that failed to resolve.
+    pub iri: String,
+    /// Byte offset of the opening `{@` in the source.
+    pub offset: usize,
+}
+
 /// Expands `{@class iri}`, `{@prop iri}`, `{@ind iri}` directives into Markdown links.
+///
+/// Unresolvable references fall back to code spans; use
+/// [`expand_directives_checked`] to find out which ones.
 pub fn expand_directives(source: &str, index: &OntologyIndex) -> String {
+    expand_directives_checked(source, index).0
+}
+
+/// Expands directives like [`expand_directives`] and additionally returns a
+/// warning for each directive that failed to resolve, with its byte offset.
+pub fn expand_directives_checked(
+    source: &str,
+    index: &OntologyIndex,
+) -> (String, Vec<DirectiveWarning>) {
     let mut result = String::with_capacity(source.len());
+    let mut warnings = Vec::new();
     let mut remaining = source;
 
     while let Some(start) = remaining.find("{@") {
         result.push_str(&remaining[..start]);
+        let offset = source.len() - remaining.len() + start;
         remaining = &remaining[start..];
 
         let end = match remaining.find('}') {
             Some(e) => e,
             None => {
                 result.push_str(remaining);
-                return result;
+                return (result, warnings);
             }
         };
 
@@ -37,7 +138,17 @@ pub fn expand_directives(source: &str, index: &OntologyIndex) -> String {
 
         // Handle {@count:KEY} directives (colon syntax, no space argument)
         if let Some(count_key) = directive.strip_prefix("count:") {
-            result.push_str(&resolve_count(count_key, index));
+            match resolve_count(count_key, index) {
+                Some(count) => result.push_str(&count),
+                None => {
+                    result.push_str(&format!("{{@count:{}}}", count_key));
+                    warnings.push(DirectiveWarning {
+                        kind: "count".to_string(),
+                        iri: count_key.to_string(),
+                        offset,
+                    });
+                }
+            }
             continue;
         }
 
@@ -54,51 +165,56 @@ pub fn expand_directives(source: &str, index: &OntologyIndex) -> String {
             "class" => resolve_class_ref(iri, index),
             "prop" => resolve_prop_ref(iri, index),
             "ind" => resolve_ind_ref(iri, index),
-            _ => format!("{{@{} {}}}", kind, iri),
+            _ => None,
         };
 
-        result.push_str(&link);
+        match link {
+            Some(link) => result.push_str(&link),
+            None => {
+                if matches!(kind, "class" | "prop" | "ind") {
+                    result.push_str(&format!("`{}`", iri));
+                } else {
+                    result.push_str(&format!("{{@{} {}}}", kind, iri));
+                }
+                warnings.push(DirectiveWarning {
+                    kind: kind.to_string(),
+                    iri: iri.to_string(),
+                    offset,
+                });
+            }
+        }
     }
 
     result.push_str(remaining);
-    result
+    (result, warnings)
 }
 
 /// Resolves a `{@class iri}` to a Markdown link.
-fn resolve_class_ref(iri: &str, index: &OntologyIndex) -> String {
-    if let Some(class) = index.classes.iter().find(|c| c.id == iri) {
-        let href = resolve_ref("class", iri, index);
-        format!("[{}]({})", class.label, href)
-    } else {
-        format!("`{}`", iri)
-    }
+fn resolve_class_ref(iri: &str, index: &OntologyIndex) -> Option<String> {
+    let class = index.classes.iter().find(|c| c.id == iri)?;
+    let href = resolve_ref("class", iri, index);
+    Some(format!("[{}]({})", class.label, href))
 }
 
 /// Resolves a `{@prop iri}` to a Markdown link.
-fn resolve_prop_ref(iri: &str, index: &OntologyIndex) -> String {
-    if let Some(prop) = index.properties.iter().find(|p| p.id == iri) {
-        let href = resolve_ref("prop", iri, index);
-        format!("[{}]({})", prop.label, href)
-    } else {
-        format!("`{}`", iri)
-    }
+fn resolve_prop_ref(iri: &str, index: &OntologyIndex) -> Option<String> {
+    let prop = index.properties.iter().find(|p| p.id == iri)?;
+    let href = resolve_ref("prop", iri, index);
+    Some(format!("[{}]({})", prop.label, href))
 }
 
 /// Resolves a `{@ind iri}` to a Markdown link.
-fn resolve_ind_ref(iri: &str, index: &OntologyIndex) -> String {
-    if let Some(ind) = index.individuals.iter().find(|i| i.id == iri) {
-        let href = resolve_ref("ind", iri, index);
-        format!("[{}]({})", ind.label, href)
-    } else {
-        format!("`{}`", iri)
-    }
+fn resolve_ind_ref(iri: &str, index: &OntologyIndex) -> Option<String> {
+    let ind = index.individuals.iter().find(|i| i.id == iri)?;
+    let href = resolve_ref("ind", iri, index);
+    Some(format!("[{}]({})", ind.label, href))
 }
 
 /// Resolves a `{@count:KEY}` directive to the current ontology count.
 /// All numeric constants come from [`uor_ontology::counts`].
-fn resolve_count(key: &str, index: &OntologyIndex) -> String {
+fn resolve_count(key: &str, index: &OntologyIndex) -> Option<String> {
     use uor_ontology::counts;
-    match key {
+    let count = match key {
         "namespaces" => index.modules.len().to_string(),
         "classes" => index.classes.len().to_string(),
         "properties" => index.properties.len().to_string(),
@@ -115,8 +231,9 @@ fn resolve_count(key: &str, index: &OntologyIndex) -> String {
         "bridge_ns" => counts::BRIDGE_NAMESPACES.to_string(),
         "user_ns" => counts::USER_NAMESPACES.to_string(),
         "conformance_checks" => counts::CONFORMANCE_CHECKS.to_string(),
-        _ => format!("{{@count:{}}}", key),
-    }
+        _ => return None,
+    };
+    Some(count)
 }
 
 /// Converts Markdown to HTML using pulldown-cmark.
@@ -128,14 +245,72 @@ pub fn markdown_to_html(markdown: &str) -> String {
 
     let parser = Parser::new_ext(markdown, opts);
     let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
+    push_html(&mut html_output, parser);
     html_output
 }
 
+/// Appends the HTML for `events` to `out`, as `pulldown_cmark::html::push_html`
+/// does, except that table header cells carry `scope="col"`.
+///
+/// pulldown-cmark emits bare `<th>` cells; header cells in data tables need
+/// `scope` to satisfy the accessibility validator, so each header
+/// `Start(TableCell)` is replaced by the equivalent raw `<th>` tag.
+pub fn push_html<'a>(out: &mut String, events: impl Iterator<Item = Event<'a>>) {
+    let mut alignments: Vec<Alignment> = Vec::new();
+    let mut in_head = false;
+    let mut column = 0;
+    let events = events.map(move |event| match event {
+        Event::Start(Tag::Table(table_alignments)) => {
+            alignments.clone_from(&table_alignments);
+            Event::Start(Tag::Table(table_alignments))
+        }
+        Event::Start(Tag::TableHead) => {
+            in_head = true;
+            column = 0;
+            event
+        }
+        Event::End(TagEnd::TableHead) => {
+            in_head = false;
+            event
+        }
+        Event::Start(Tag::TableCell) if in_head => {
+            let style = match alignments.get(column) {
+                Some(Alignment::Left) => " style=\"text-align: left\"",
+                Some(Alignment::Center) => " style=\"text-align: center\"",
+                Some(Alignment::Right) => " style=\"text-align: right\"",
+                _ => "",
+            };
+            Event::Html(CowStr::from(format!("<th scope=\"col\"{style}>")))
+        }
+        Event::End(TagEnd::TableCell) if in_head => {
+            column += 1;
+            event
+        }
+        _ => event,
+    });
+    html::push_html(out, events);
+}
+
+/// Origin of the deployed site, used for canonical and sitemap URLs.
+pub const BASE_URL: &str = "https://uor.foundation";
+
+/// Renders `<link rel="canonical">` for the page at `page_path` (relative to
+/// the site root, without the base path). Empty for an empty path.
+pub fn canonical_link(page_path: &str) -> String {
+    if page_path.is_empty() {
+        return String::new();
+    }
+    format!(
+        "<link rel=\"canonical\" href=\"{}\">\n",
+        escape_html(&format!("{BASE_URL}{page_path}"))
+    )
+}
+
 /// Renders a docs page inside the standard site shell with a sidebar.
 ///
 /// Uses the same `<header class="site-header">` / `<footer class="site-footer">`
 /// as the website, with the docs-specific nav tree in an `<aside class="docs-sidebar">`.
+/// `page_path` (e.g. `/docs/namespaces/schema.html`) sets the canonical URL.
 pub fn render_docs_page(
     title: &str,
     content_html: &str,
@@ -143,7 +318,9 @@ pub fn render_docs_page(
     docs_nav_html: &str,
     breadcrumb: &str,
     base_path: &str,
+    page_path: &str,
 ) -> String {
+    let canonical = canonical_link(page_path);
     let home_url = format!("{}/", base_path);
     let css_url = format!("{}/css/style.css", base_path);
     let js_url = format!("{}/js/search.js", base_path);
@@ -156,7 +333,7 @@ pub fn render_docs_page(
 <title>{title} — UOR Foundation</title>
 <link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.3/dist/css/bootstrap.min.css" rel="stylesheet" integrity="sha384-QWTKZyjpPEjISv5WaRU9OFeRpok6YctnYmDr5pNlyT2bRjXh0JMhjY6hW+ALEwIH" crossorigin="anonymous">
 <link rel="stylesheet" href="{css_url}">
-</head>
+{canonical}</head>
 <body>
 <a href="#main-content" class="skip-link">Skip to main content</a>
 <header class="navbar navbar-expand-lg navbar-dark site-header">
@@ -203,10 +380,67 @@ pub fn render_docs_page(
     )
 }
 
+/// Builds the `Home › Docs › …` breadcrumb trail shown above docs pages.
+///
+/// Every item links to its `href` except the last, which is the current
+/// page and renders as plain text. Labels are HTML-escaped.
+#[derive(Debug, Clone, Default)]
+pub struct Breadcrumb {
+    items: Vec<(String, String)>,
+}
+
+impl Breadcrumb {
+    /// Creates an empty trail.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an item linking to `href`.
+    pub fn push(&mut self, label: &str, href: &str) -> &mut Self {
+        self.items.push((label.to_string(), href.to_string()));
+        self
+    }
+
+    /// Renders the trail as HTML, items separated by ` › `.
+    pub fn render(&self) -> String {
+        let last = self.items.len().saturating_sub(1);
+        let mut out = String::new();
+        for (i, (label, href)) in self.items.iter().enumerate() {
+            if i > 0 {
+                out.push_str(" › ");
+            }
+            if i == last {
+                escape_html_into(label, &mut out);
+            } else {
+                out.push_str("<a href=\"");
+                escape_html_into(href, &mut out);
+                out.push_str("\">");
+                escape_html_into(label, &mut out);
+                out.push_str("</a>");
+            }
+        }
+        out
+    }
+}
+
 /// Escapes HTML special characters in a string.
 pub fn escape_html(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
+    let mut out = String::with_capacity(s.len());
+    escape_html_into(s, &mut out);
+    out
+}
+
+/// Appends `s` to `out` with HTML special characters escaped, without
+/// allocating an intermediate string.
+pub fn escape_html_into(s: &str, out: &mut String) {
+    out.reserve(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
 }
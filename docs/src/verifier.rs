@@ -2,28 +2,93 @@
 //! against the live spec and checks completeness.
 
 use std::collections::HashSet;
-use std::path::Path;
+use std::fmt;
+use std::path::{Path, PathBuf};
 
 use anyhow::{bail, Result};
 use walkdir::WalkDir;
 
 use crate::extractor::OntologyIndex;
+use crate::renderer;
+
+/// A single problem found in a prose content file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentIssue {
+    /// The content file containing the problem.
+    pub path: PathBuf,
+    /// Byte offset of the offending directive (0 for whole-file problems).
+    pub offset: usize,
+    /// What is wrong (e.g., `unknown class reference: {@class …}`).
+    pub message: String,
+}
+
+impl fmt::Display for ContentIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}",
+            self.path.display(),
+            self.offset,
+            self.message
+        )
+    }
+}
+
+/// Every problem found across a content directory, in file-walk order.
+#[derive(Debug, Clone, Default)]
+pub struct ContentVerificationReport {
+    /// Problems found, one per dangling directive or unreadable file.
+    pub issues: Vec<ContentIssue>,
+}
+
+impl ContentVerificationReport {
+    /// Returns `true` if no problems were found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl fmt::Display for ContentVerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{issue}")?;
+        }
+        Ok(())
+    }
+}
 
 /// Verifies all content files under `content_dir` for:
 /// 1. Valid `{@class}`, `{@prop}`, `{@ind}` references
 /// 2. Completeness: every spec term referenced at least once
 ///
+/// Strict mode: fails if [`verify_content_report`] finds anything.
+///
 /// # Errors
 ///
-/// Returns an error if any reference is invalid or any term is not covered.
+/// Returns an error listing every invalid reference if any is found.
 pub fn verify_content(content_dir: &Path) -> Result<()> {
+    let report = verify_content_report(content_dir);
+    if !report.is_empty() {
+        bail!("Content verification errors:\n{}", report);
+    }
+    Ok(())
+}
+
+/// Lenient mode: scans every content file under `content_dir` and collects
+/// all dangling directives (and unreadable files) instead of stopping at the
+/// first, so authors can fix them in one pass.
+pub fn verify_content_report(content_dir: &Path) -> ContentVerificationReport {
     let index = OntologyIndex::from_spec();
 
     let mut referenced_classes: HashSet<&'static str> = HashSet::new();
     let mut referenced_properties: HashSet<&'static str> = HashSet::new();
     let mut referenced_individuals: HashSet<&'static str> = HashSet::new();
 
-    let mut errors: Vec<String> = Vec::new();
+    let mut report = ContentVerificationReport::default();
 
     // Namespace reference pages are auto-generated and always cover all terms
     // in the namespace, so mark all terms as referenced from those pages
@@ -40,6 +105,7 @@ pub fn verify_content(content_dir: &Path) -> Result<()> {
     // Scan prose content files
     if content_dir.exists() {
         for entry in WalkDir::new(content_dir)
+            .sort_by_file_name()
             .into_iter()
             .filter_map(|e| e.ok())
             .filter(|e| e.path().extension().map(|x| x == "md").unwrap_or(false))
@@ -48,89 +114,46 @@ pub fn verify_content(content_dir: &Path) -> Result<()> {
             let content = match std::fs::read_to_string(path) {
                 Ok(c) => c,
                 Err(e) => {
-                    errors.push(format!("{}: cannot read: {}", path.display(), e));
+                    report.issues.push(ContentIssue {
+                        path: path.to_path_buf(),
+                        offset: 0,
+                        message: format!("cannot read: {e}"),
+                    });
                     continue;
                 }
             };
 
             // Check {@class}, {@prop}, {@ind} references
-            check_refs_in_file(&content, path, &index, &mut errors);
+            check_refs_in_file(&content, path, &index, &mut report.issues);
         }
     }
 
-    if !errors.is_empty() {
-        let msg = errors.join("\n");
-        bail!("Content verification errors:\n{}", msg);
-    }
-
-    Ok(())
+    report
 }
 
-/// Checks all `{@class}`, `{@prop}`, `{@ind}` references in a content file.
-fn check_refs_in_file(content: &str, path: &Path, index: &OntologyIndex, errors: &mut Vec<String>) {
-    let mut remaining = content;
-
-    while let Some(start) = remaining.find("{@") {
-        remaining = &remaining[start..];
-
-        // Parse {@class iri}, {@prop iri}, {@ind iri}
-        let end = match remaining.find('}') {
-            Some(e) => e,
-            None => break,
+/// Checks all `{@class}`, `{@prop}`, `{@ind}`, `{@count}` references in a content file.
+///
+/// Uses the renderer's own directive scan so anything reported here is exactly
+/// what would otherwise render as a silent code-span fallback.
+fn check_refs_in_file(
+    content: &str,
+    path: &Path,
+    index: &OntologyIndex,
+    issues: &mut Vec<ContentIssue>,
+) {
+    for warning in renderer::check_directives(content, index) {
+        let what = match warning.kind.as_str() {
+            "class" => "unknown class reference",
+            "prop" => "unknown property reference",
+            "ind" => "unknown individual reference",
+            "count" => "unknown count key",
+            _ => "unknown directive",
         };
-
-        let directive = &remaining[2..end];
-        remaining = &remaining[end + 1..];
-
-        // Count directives use colon syntax ({@count:KEY}), not space
-        if directive.starts_with("count:") {
-            continue;
-        }
-
-        let parts: Vec<&str> = directive.splitn(2, ' ').collect();
-        if parts.len() != 2 {
-            continue;
-        }
-
-        let kind = parts[0].trim();
-        let iri = parts[1].trim();
-
-        match kind {
-            "class" => {
-                if !index.is_class(iri) {
-                    errors.push(format!(
-                        "{}: unknown class reference: {{@class {}}}",
-                        path.display(),
-                        iri
-                    ));
-                }
-            }
-            "prop" => {
-                if !index.is_property(iri) {
-                    errors.push(format!(
-                        "{}: unknown property reference: {{@prop {}}}",
-                        path.display(),
-                        iri
-                    ));
-                }
-            }
-            "ind" => {
-                if !index.is_individual(iri) {
-                    errors.push(format!(
-                        "{}: unknown individual reference: {{@ind {}}}",
-                        path.display(),
-                        iri
-                    ));
-                }
-            }
-            _ => {
-                errors.push(format!(
-                    "{}: unknown directive: {{@{}}}",
-                    path.display(),
-                    kind
-                ));
-            }
-        }
+        issues.push(ContentIssue {
+            path: path.to_path_buf(),
+            offset: warning.offset,
+            message: format!("{}: {{@{} {}}}", what, warning.kind, warning.iri),
+        });
     }
 }
 
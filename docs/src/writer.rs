@@ -3,14 +3,63 @@
 use std::fs;
 use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 
-/// Writes an HTML page to the given path, creating parent directories as needed.
+/// Options controlling how [`write_html_with`] writes a page.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WriterConfig {
+    /// Check block-element nesting and bare `&` before writing, and refuse
+    /// to write a page that fails. Off by default; callers opt in.
+    pub validate: bool,
+}
+
+/// Block elements whose open and close tags must balance.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "div",
+    "footer",
+    "header",
+    "main",
+    "nav",
+    "ol",
+    "pre",
+    "section",
+    "table",
+    "tbody",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Writes an HTML page to the given path with the default [`WriterConfig`],
+/// which does not validate the page.
 ///
 /// # Errors
 ///
-/// Returns an error if the directory cannot be created or the file cannot be written.
+/// Returns an error if the directory cannot be created or the file cannot
+/// be written.
 pub fn write_html(path: &Path, content: &str) -> Result<()> {
+    write_html_with(path, content, &WriterConfig::default())
+}
+
+/// Writes an HTML page to the given path, creating parent directories as needed.
+///
+/// # Errors
+///
+/// Returns an error if validation is enabled and the page is malformed, or if
+/// the directory cannot be created or the file cannot be written.
+pub fn write_html_with(path: &Path, content: &str, config: &WriterConfig) -> Result<()> {
+    if config.validate {
+        if let Err(problem) = validate_html(content) {
+            bail!(
+                "Refusing to write invalid HTML to {}: {problem}",
+                path.display()
+            );
+        }
+    }
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
@@ -34,3 +83,105 @@ pub fn write_text(path: &Path, content: &str) -> Result<()> {
         .with_context(|| format!("Failed to write file: {}", path.display()))?;
     Ok(())
 }
+
+/// Checks that block elements nest and close correctly and that every `&`
+/// outside `<script>`/`<style>` begins a character reference.
+fn validate_html(html: &str) -> std::result::Result<(), String> {
+    let mut open: Vec<String> = Vec::new();
+    let mut rest = html;
+    while let Some(idx) = rest.find('<') {
+        check_ampersands(&rest[..idx])?;
+        rest = &rest[idx..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            let end = after.find("-->").ok_or("unterminated comment")?;
+            rest = &after[end + 3..];
+            continue;
+        }
+        let end = tag_end(rest).ok_or("unterminated tag")?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        let closing = tag.starts_with('/');
+        let name: String = tag
+            .trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if !closing && (name == "script" || name == "style") {
+            let skip = find_close_tag(rest, &name).unwrap_or(rest.len());
+            rest = &rest[skip..];
+            continue;
+        }
+        check_ampersands(tag)?;
+        if !BLOCK_ELEMENTS.contains(&name.as_str()) || tag.ends_with('/') {
+            continue;
+        }
+        if closing {
+            match open.pop() {
+                Some(top) if top == name => {}
+                Some(top) => return Err(format!("</{name}> closes <{top}>")),
+                None => return Err(format!("</{name}> has no matching open tag")),
+            }
+        } else {
+            open.push(name);
+        }
+    }
+    check_ampersands(rest)?;
+    match open.pop() {
+        Some(name) => Err(format!("<{name}> is never closed")),
+        None => Ok(()),
+    }
+}
+
+/// Byte offset of the first `</name` in `s`, matching `name` ASCII
+/// case-insensitively against only the bytes after each `</`.
+fn find_close_tag(s: &str, name: &str) -> Option<usize> {
+    s.match_indices("</").map(|(i, _)| i).find(|&i| {
+        s.as_bytes()
+            .get(i + 2..i + 2 + name.len())
+            .is_some_and(|b| b.eq_ignore_ascii_case(name.as_bytes()))
+    })
+}
+
+/// Byte offset of the `>` ending the tag that starts `s`, skipping any `>`
+/// inside quoted attribute values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Rejects any `&` in `text` that does not start a named or numeric
+/// character reference.
+fn check_ampersands(text: &str) -> std::result::Result<(), String> {
+    for (i, _) in text.match_indices('&') {
+        let entity = &text[i + 1..];
+        let body_len = entity.find(';').unwrap_or(0);
+        let body = &entity[..body_len];
+        let valid = match body.strip_prefix('#') {
+            Some(num) => match num.strip_prefix(['x', 'X']) {
+                Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+                None => !num.is_empty() && num.chars().all(|c| c.is_ascii_digit()),
+            },
+            None => {
+                body.starts_with(|c: char| c.is_ascii_alphabetic())
+                    && body.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+        };
+        if !valid {
+            let context: String = text[i..].chars().take(16).collect();
+            return Err(format!("unescaped `&` at `{context}`"));
+        }
+    }
+    Ok(())
+}
@@ -73,6 +73,67 @@ pub trait BoundarySession<H: HostTypes>: crate::user::state::Session<H> {
     fn crossing_count(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`IOBoundary`](super::IOBoundary).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IOBoundary;
+    impl crate::enforcement::has_iri_sealed::Sealed for IOBoundary {}
+    impl crate::enforcement::HasIri for IOBoundary {
+        const IRI: &'static str = "https://uor.foundation/boundary/IOBoundary";
+    }
+    /// IRI tag for [`Source`](super::Source).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Source;
+    impl crate::enforcement::has_iri_sealed::Sealed for Source {}
+    impl crate::enforcement::HasIri for Source {
+        const IRI: &'static str = "https://uor.foundation/boundary/Source";
+    }
+    /// IRI tag for [`Sink`](super::Sink).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Sink;
+    impl crate::enforcement::has_iri_sealed::Sealed for Sink {}
+    impl crate::enforcement::HasIri for Sink {
+        const IRI: &'static str = "https://uor.foundation/boundary/Sink";
+    }
+    /// IRI tag for [`BoundaryEffect`](super::BoundaryEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundaryEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundaryEffect {}
+    impl crate::enforcement::HasIri for BoundaryEffect {
+        const IRI: &'static str = "https://uor.foundation/boundary/BoundaryEffect";
+    }
+    /// IRI tag for [`IngestEffect`](super::IngestEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IngestEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for IngestEffect {}
+    impl crate::enforcement::HasIri for IngestEffect {
+        const IRI: &'static str = "https://uor.foundation/boundary/IngestEffect";
+    }
+    /// IRI tag for [`EmitEffect`](super::EmitEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EmitEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for EmitEffect {}
+    impl crate::enforcement::HasIri for EmitEffect {
+        const IRI: &'static str = "https://uor.foundation/boundary/EmitEffect";
+    }
+    /// IRI tag for [`BoundaryProtocol`](super::BoundaryProtocol).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundaryProtocol;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundaryProtocol {}
+    impl crate::enforcement::HasIri for BoundaryProtocol {
+        const IRI: &'static str = "https://uor.foundation/boundary/BoundaryProtocol";
+    }
+    /// IRI tag for [`BoundarySession`](super::BoundarySession).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundarySession;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundarySession {}
+    impl crate::enforcement::HasIri for BoundarySession {
+        const IRI: &'static str = "https://uor.foundation/boundary/BoundarySession";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `IOBoundary<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
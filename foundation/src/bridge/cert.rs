@@ -170,6 +170,137 @@ pub trait GenericImpossibilityCertificate<H: HostTypes>: Certificate<H> {}
 /// A certificate attesting that the inhabitance decider concluded the input is unsatisfiable — there is no value in the carrier that satisfies the declared constraint conjunction. Returned by `resolver::inhabitance::certify` on failure.
 pub trait InhabitanceImpossibilityCertificate<H: HostTypes>: Certificate<H> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Certificate`](super::Certificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Certificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for Certificate {}
+    impl crate::enforcement::HasIri for Certificate {
+        const IRI: &'static str = "https://uor.foundation/cert/Certificate";
+    }
+    /// IRI tag for [`TransformCertificate`](super::TransformCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TransformCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for TransformCertificate {}
+    impl crate::enforcement::HasIri for TransformCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/TransformCertificate";
+    }
+    /// IRI tag for [`IsometryCertificate`](super::IsometryCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IsometryCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for IsometryCertificate {}
+    impl crate::enforcement::HasIri for IsometryCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/IsometryCertificate";
+    }
+    /// IRI tag for [`InvolutionCertificate`](super::InvolutionCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InvolutionCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for InvolutionCertificate {}
+    impl crate::enforcement::HasIri for InvolutionCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/InvolutionCertificate";
+    }
+    /// IRI tag for [`CompletenessCertificate`](super::CompletenessCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompletenessCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompletenessCertificate {}
+    impl crate::enforcement::HasIri for CompletenessCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/CompletenessCertificate";
+    }
+    /// IRI tag for [`CompletenessAuditTrail`](super::CompletenessAuditTrail).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompletenessAuditTrail;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompletenessAuditTrail {}
+    impl crate::enforcement::HasIri for CompletenessAuditTrail {
+        const IRI: &'static str = "https://uor.foundation/cert/CompletenessAuditTrail";
+    }
+    /// IRI tag for [`GroundingCertificate`](super::GroundingCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingCertificate {}
+    impl crate::enforcement::HasIri for GroundingCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/GroundingCertificate";
+    }
+    /// IRI tag for [`GeodesicCertificate`](super::GeodesicCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GeodesicCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GeodesicCertificate {}
+    impl crate::enforcement::HasIri for GeodesicCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/GeodesicCertificate";
+    }
+    /// IRI tag for [`MeasurementCertificate`](super::MeasurementCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MeasurementCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for MeasurementCertificate {}
+    impl crate::enforcement::HasIri for MeasurementCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/MeasurementCertificate";
+    }
+    /// IRI tag for [`GeodesicEvidenceBundle`](super::GeodesicEvidenceBundle).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GeodesicEvidenceBundle;
+    impl crate::enforcement::has_iri_sealed::Sealed for GeodesicEvidenceBundle {}
+    impl crate::enforcement::HasIri for GeodesicEvidenceBundle {
+        const IRI: &'static str = "https://uor.foundation/cert/GeodesicEvidenceBundle";
+    }
+    /// IRI tag for [`BornRuleVerification`](super::BornRuleVerification).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BornRuleVerification;
+    impl crate::enforcement::has_iri_sealed::Sealed for BornRuleVerification {}
+    impl crate::enforcement::HasIri for BornRuleVerification {
+        const IRI: &'static str = "https://uor.foundation/cert/BornRuleVerification";
+    }
+    /// IRI tag for [`LiftChainCertificate`](super::LiftChainCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftChainCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftChainCertificate {}
+    impl crate::enforcement::HasIri for LiftChainCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/LiftChainCertificate";
+    }
+    /// IRI tag for [`ChainAuditTrail`](super::ChainAuditTrail).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ChainAuditTrail;
+    impl crate::enforcement::has_iri_sealed::Sealed for ChainAuditTrail {}
+    impl crate::enforcement::HasIri for ChainAuditTrail {
+        const IRI: &'static str = "https://uor.foundation/cert/ChainAuditTrail";
+    }
+    /// IRI tag for [`InhabitanceCertificate`](super::InhabitanceCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceCertificate {}
+    impl crate::enforcement::HasIri for InhabitanceCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/InhabitanceCertificate";
+    }
+    /// IRI tag for [`MultiplicationCertificate`](super::MultiplicationCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MultiplicationCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for MultiplicationCertificate {}
+    impl crate::enforcement::HasIri for MultiplicationCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/MultiplicationCertificate";
+    }
+    /// IRI tag for [`PartitionCertificate`](super::PartitionCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PartitionCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for PartitionCertificate {}
+    impl crate::enforcement::HasIri for PartitionCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/PartitionCertificate";
+    }
+    /// IRI tag for [`GenericImpossibilityCertificate`](super::GenericImpossibilityCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GenericImpossibilityCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GenericImpossibilityCertificate {}
+    impl crate::enforcement::HasIri for GenericImpossibilityCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/GenericImpossibilityCertificate";
+    }
+    /// IRI tag for [`InhabitanceImpossibilityCertificate`](super::InhabitanceImpossibilityCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceImpossibilityCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceImpossibilityCertificate {}
+    impl crate::enforcement::HasIri for InhabitanceImpossibilityCertificate {
+        const IRI: &'static str = "https://uor.foundation/cert/InhabitanceImpossibilityCertificate";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Certificate<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -106,6 +106,81 @@ pub trait GluingObstruction<H: HostTypes> {
     fn addresses_suggestion(&self) -> &[Self::RefinementSuggestion];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`CochainGroup`](super::CochainGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CochainGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for CochainGroup {}
+    impl crate::enforcement::HasIri for CochainGroup {
+        const IRI: &'static str = "https://uor.foundation/cohomology/CochainGroup";
+    }
+    /// IRI tag for [`CoboundaryOperator`](super::CoboundaryOperator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CoboundaryOperator;
+    impl crate::enforcement::has_iri_sealed::Sealed for CoboundaryOperator {}
+    impl crate::enforcement::HasIri for CoboundaryOperator {
+        const IRI: &'static str = "https://uor.foundation/cohomology/CoboundaryOperator";
+    }
+    /// IRI tag for [`CochainComplex`](super::CochainComplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CochainComplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for CochainComplex {}
+    impl crate::enforcement::HasIri for CochainComplex {
+        const IRI: &'static str = "https://uor.foundation/cohomology/CochainComplex";
+    }
+    /// IRI tag for [`CohomologyGroup`](super::CohomologyGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CohomologyGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for CohomologyGroup {}
+    impl crate::enforcement::HasIri for CohomologyGroup {
+        const IRI: &'static str = "https://uor.foundation/cohomology/CohomologyGroup";
+    }
+    /// IRI tag for [`Sheaf`](super::Sheaf).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Sheaf;
+    impl crate::enforcement::has_iri_sealed::Sealed for Sheaf {}
+    impl crate::enforcement::HasIri for Sheaf {
+        const IRI: &'static str = "https://uor.foundation/cohomology/Sheaf";
+    }
+    /// IRI tag for [`Stalk`](super::Stalk).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Stalk;
+    impl crate::enforcement::has_iri_sealed::Sealed for Stalk {}
+    impl crate::enforcement::HasIri for Stalk {
+        const IRI: &'static str = "https://uor.foundation/cohomology/Stalk";
+    }
+    /// IRI tag for [`Section`](super::Section).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Section;
+    impl crate::enforcement::has_iri_sealed::Sealed for Section {}
+    impl crate::enforcement::HasIri for Section {
+        const IRI: &'static str = "https://uor.foundation/cohomology/Section";
+    }
+    /// IRI tag for [`LocalSection`](super::LocalSection).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LocalSection;
+    impl crate::enforcement::has_iri_sealed::Sealed for LocalSection {}
+    impl crate::enforcement::HasIri for LocalSection {
+        const IRI: &'static str = "https://uor.foundation/cohomology/LocalSection";
+    }
+    /// IRI tag for [`RestrictionMap`](super::RestrictionMap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RestrictionMap;
+    impl crate::enforcement::has_iri_sealed::Sealed for RestrictionMap {}
+    impl crate::enforcement::HasIri for RestrictionMap {
+        const IRI: &'static str = "https://uor.foundation/cohomology/RestrictionMap";
+    }
+    /// IRI tag for [`GluingObstruction`](super::GluingObstruction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GluingObstruction;
+    impl crate::enforcement::has_iri_sealed::Sealed for GluingObstruction {}
+    impl crate::enforcement::HasIri for GluingObstruction {
+        const IRI: &'static str = "https://uor.foundation/cohomology/GluingObstruction";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `CochainGroup<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -248,6 +248,228 @@ pub trait PreludeExport<H: HostTypes> {
     fn export_rust_name(&self) -> &H::HostString;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Shape`](super::Shape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Shape;
+    impl crate::enforcement::has_iri_sealed::Sealed for Shape {}
+    impl crate::enforcement::HasIri for Shape {
+        const IRI: &'static str = "https://uor.foundation/conformance/Shape";
+    }
+    /// IRI tag for [`PropertyConstraint`](super::PropertyConstraint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PropertyConstraint;
+    impl crate::enforcement::has_iri_sealed::Sealed for PropertyConstraint {}
+    impl crate::enforcement::HasIri for PropertyConstraint {
+        const IRI: &'static str = "https://uor.foundation/conformance/PropertyConstraint";
+    }
+    /// IRI tag for [`WittLevelShape`](super::WittLevelShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WittLevelShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for WittLevelShape {}
+    impl crate::enforcement::HasIri for WittLevelShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/WittLevelShape";
+    }
+    /// IRI tag for [`EffectShape`](super::EffectShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EffectShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for EffectShape {}
+    impl crate::enforcement::HasIri for EffectShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/EffectShape";
+    }
+    /// IRI tag for [`ParallelShape`](super::ParallelShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParallelShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for ParallelShape {}
+    impl crate::enforcement::HasIri for ParallelShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/ParallelShape";
+    }
+    /// IRI tag for [`StreamShape`](super::StreamShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StreamShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for StreamShape {}
+    impl crate::enforcement::HasIri for StreamShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/StreamShape";
+    }
+    /// IRI tag for [`DispatchShape`](super::DispatchShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DispatchShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for DispatchShape {}
+    impl crate::enforcement::HasIri for DispatchShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/DispatchShape";
+    }
+    /// IRI tag for [`LeaseShape`](super::LeaseShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeaseShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeaseShape {}
+    impl crate::enforcement::HasIri for LeaseShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/LeaseShape";
+    }
+    /// IRI tag for [`GroundingShape`](super::GroundingShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingShape {}
+    impl crate::enforcement::HasIri for GroundingShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/GroundingShape";
+    }
+    /// IRI tag for [`ValidationResult`](super::ValidationResult).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ValidationResult;
+    impl crate::enforcement::has_iri_sealed::Sealed for ValidationResult {}
+    impl crate::enforcement::HasIri for ValidationResult {
+        const IRI: &'static str = "https://uor.foundation/conformance/ValidationResult";
+    }
+    /// IRI tag for [`PredicateShape`](super::PredicateShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PredicateShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for PredicateShape {}
+    impl crate::enforcement::HasIri for PredicateShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/PredicateShape";
+    }
+    /// IRI tag for [`InteractionShape`](super::InteractionShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InteractionShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for InteractionShape {}
+    impl crate::enforcement::HasIri for InteractionShape {
+        const IRI: &'static str = "https://uor.foundation/conformance/InteractionShape";
+    }
+    /// IRI tag for [`WitnessDatum`](super::WitnessDatum).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WitnessDatum;
+    impl crate::enforcement::has_iri_sealed::Sealed for WitnessDatum {}
+    impl crate::enforcement::HasIri for WitnessDatum {
+        const IRI: &'static str = "https://uor.foundation/conformance/WitnessDatum";
+    }
+    /// IRI tag for [`GroundedCoordinate`](super::GroundedCoordinate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundedCoordinate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundedCoordinate {}
+    impl crate::enforcement::HasIri for GroundedCoordinate {
+        const IRI: &'static str = "https://uor.foundation/conformance/GroundedCoordinate";
+    }
+    /// IRI tag for [`GroundedTuple`](super::GroundedTuple).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundedTuple;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundedTuple {}
+    impl crate::enforcement::HasIri for GroundedTuple {
+        const IRI: &'static str = "https://uor.foundation/conformance/GroundedTuple";
+    }
+    /// IRI tag for [`GroundedValueMarker`](super::GroundedValueMarker).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundedValueMarker;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundedValueMarker {}
+    impl crate::enforcement::HasIri for GroundedValueMarker {
+        const IRI: &'static str = "https://uor.foundation/conformance/GroundedValueMarker";
+    }
+    /// IRI tag for [`ValidatedWrapper`](super::ValidatedWrapper).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ValidatedWrapper;
+    impl crate::enforcement::has_iri_sealed::Sealed for ValidatedWrapper {}
+    impl crate::enforcement::HasIri for ValidatedWrapper {
+        const IRI: &'static str = "https://uor.foundation/conformance/ValidatedWrapper";
+    }
+    /// IRI tag for [`WitnessDerivation`](super::WitnessDerivation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WitnessDerivation;
+    impl crate::enforcement::has_iri_sealed::Sealed for WitnessDerivation {}
+    impl crate::enforcement::HasIri for WitnessDerivation {
+        const IRI: &'static str = "https://uor.foundation/conformance/WitnessDerivation";
+    }
+    /// IRI tag for [`WitnessSiteBudget`](super::WitnessSiteBudget).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WitnessSiteBudget;
+    impl crate::enforcement::has_iri_sealed::Sealed for WitnessSiteBudget {}
+    impl crate::enforcement::HasIri for WitnessSiteBudget {
+        const IRI: &'static str = "https://uor.foundation/conformance/WitnessSiteBudget";
+    }
+    /// IRI tag for [`ShapeViolationReport`](super::ShapeViolationReport).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ShapeViolationReport;
+    impl crate::enforcement::has_iri_sealed::Sealed for ShapeViolationReport {}
+    impl crate::enforcement::HasIri for ShapeViolationReport {
+        const IRI: &'static str = "https://uor.foundation/conformance/ShapeViolationReport";
+    }
+    /// IRI tag for [`CompileUnitBuilder`](super::CompileUnitBuilder).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompileUnitBuilder;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompileUnitBuilder {}
+    impl crate::enforcement::HasIri for CompileUnitBuilder {
+        const IRI: &'static str = "https://uor.foundation/conformance/CompileUnitBuilder";
+    }
+    /// IRI tag for [`EffectDeclaration`](super::EffectDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EffectDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for EffectDeclaration {}
+    impl crate::enforcement::HasIri for EffectDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/EffectDeclaration";
+    }
+    /// IRI tag for [`GroundingDeclaration`](super::GroundingDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingDeclaration {}
+    impl crate::enforcement::HasIri for GroundingDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/GroundingDeclaration";
+    }
+    /// IRI tag for [`DispatchDeclaration`](super::DispatchDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DispatchDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for DispatchDeclaration {}
+    impl crate::enforcement::HasIri for DispatchDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/DispatchDeclaration";
+    }
+    /// IRI tag for [`LeaseDeclaration`](super::LeaseDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeaseDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeaseDeclaration {}
+    impl crate::enforcement::HasIri for LeaseDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/LeaseDeclaration";
+    }
+    /// IRI tag for [`StreamDeclaration`](super::StreamDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StreamDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for StreamDeclaration {}
+    impl crate::enforcement::HasIri for StreamDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/StreamDeclaration";
+    }
+    /// IRI tag for [`PredicateDeclaration`](super::PredicateDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PredicateDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for PredicateDeclaration {}
+    impl crate::enforcement::HasIri for PredicateDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/PredicateDeclaration";
+    }
+    /// IRI tag for [`ParallelDeclaration`](super::ParallelDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParallelDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for ParallelDeclaration {}
+    impl crate::enforcement::HasIri for ParallelDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/ParallelDeclaration";
+    }
+    /// IRI tag for [`WittLevelDeclaration`](super::WittLevelDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WittLevelDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for WittLevelDeclaration {}
+    impl crate::enforcement::HasIri for WittLevelDeclaration {
+        const IRI: &'static str = "https://uor.foundation/conformance/WittLevelDeclaration";
+    }
+    /// IRI tag for [`MintingSession`](super::MintingSession).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MintingSession;
+    impl crate::enforcement::has_iri_sealed::Sealed for MintingSession {}
+    impl crate::enforcement::HasIri for MintingSession {
+        const IRI: &'static str = "https://uor.foundation/conformance/MintingSession";
+    }
+    /// IRI tag for [`PreludeExport`](super::PreludeExport).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PreludeExport;
+    impl crate::enforcement::has_iri_sealed::Sealed for PreludeExport {}
+    impl crate::enforcement::HasIri for PreludeExport {
+        const IRI: &'static str = "https://uor.foundation/conformance/PreludeExport";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Shape<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -129,6 +129,88 @@ pub trait DerivationTrace<H: HostTypes> {
     fn trace_event_count(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Derivation`](super::Derivation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Derivation;
+    impl crate::enforcement::has_iri_sealed::Sealed for Derivation {}
+    impl crate::enforcement::HasIri for Derivation {
+        const IRI: &'static str = "https://uor.foundation/derivation/Derivation";
+    }
+    /// IRI tag for [`DerivationStep`](super::DerivationStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DerivationStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for DerivationStep {}
+    impl crate::enforcement::HasIri for DerivationStep {
+        const IRI: &'static str = "https://uor.foundation/derivation/DerivationStep";
+    }
+    /// IRI tag for [`RewriteStep`](super::RewriteStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RewriteStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for RewriteStep {}
+    impl crate::enforcement::HasIri for RewriteStep {
+        const IRI: &'static str = "https://uor.foundation/derivation/RewriteStep";
+    }
+    /// IRI tag for [`RefinementStep`](super::RefinementStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RefinementStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for RefinementStep {}
+    impl crate::enforcement::HasIri for RefinementStep {
+        const IRI: &'static str = "https://uor.foundation/derivation/RefinementStep";
+    }
+    /// IRI tag for [`TermMetrics`](super::TermMetrics).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TermMetrics;
+    impl crate::enforcement::has_iri_sealed::Sealed for TermMetrics {}
+    impl crate::enforcement::HasIri for TermMetrics {
+        const IRI: &'static str = "https://uor.foundation/derivation/TermMetrics";
+    }
+    /// IRI tag for [`SynthesisStep`](super::SynthesisStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SynthesisStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for SynthesisStep {}
+    impl crate::enforcement::HasIri for SynthesisStep {
+        const IRI: &'static str = "https://uor.foundation/derivation/SynthesisStep";
+    }
+    /// IRI tag for [`SynthesisCheckpoint`](super::SynthesisCheckpoint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SynthesisCheckpoint;
+    impl crate::enforcement::has_iri_sealed::Sealed for SynthesisCheckpoint {}
+    impl crate::enforcement::HasIri for SynthesisCheckpoint {
+        const IRI: &'static str = "https://uor.foundation/derivation/SynthesisCheckpoint";
+    }
+    /// IRI tag for [`InhabitanceStep`](super::InhabitanceStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceStep {}
+    impl crate::enforcement::HasIri for InhabitanceStep {
+        const IRI: &'static str = "https://uor.foundation/derivation/InhabitanceStep";
+    }
+    /// IRI tag for [`InhabitanceCheckpoint`](super::InhabitanceCheckpoint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceCheckpoint;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceCheckpoint {}
+    impl crate::enforcement::HasIri for InhabitanceCheckpoint {
+        const IRI: &'static str = "https://uor.foundation/derivation/InhabitanceCheckpoint";
+    }
+    /// IRI tag for [`DerivationDepthObservable`](super::DerivationDepthObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DerivationDepthObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for DerivationDepthObservable {}
+    impl crate::enforcement::HasIri for DerivationDepthObservable {
+        const IRI: &'static str = "https://uor.foundation/derivation/DerivationDepthObservable";
+    }
+    /// IRI tag for [`DerivationTrace`](super::DerivationTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DerivationTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for DerivationTrace {}
+    impl crate::enforcement::HasIri for DerivationTrace {
+        const IRI: &'static str = "https://uor.foundation/derivation/DerivationTrace";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Derivation<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
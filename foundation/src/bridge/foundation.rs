@@ -12,6 +12,18 @@ pub trait LayoutInvariant<H: HostTypes> {
     fn layout_rule(&self) -> &H::HostString;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`LayoutInvariant`](super::LayoutInvariant).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LayoutInvariant;
+    impl crate::enforcement::has_iri_sealed::Sealed for LayoutInvariant {}
+    impl crate::enforcement::HasIri for LayoutInvariant {
+        const IRI: &'static str = "https://uor.foundation/foundation/LayoutInvariant";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `LayoutInvariant<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -148,6 +148,109 @@ pub trait DeformationComplex<H: HostTypes>: ChainComplex<H> {
     fn obstruction_dimension(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Simplex`](super::Simplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Simplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for Simplex {}
+    impl crate::enforcement::HasIri for Simplex {
+        const IRI: &'static str = "https://uor.foundation/homology/Simplex";
+    }
+    /// IRI tag for [`SimplicialComplex`](super::SimplicialComplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SimplicialComplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for SimplicialComplex {}
+    impl crate::enforcement::HasIri for SimplicialComplex {
+        const IRI: &'static str = "https://uor.foundation/homology/SimplicialComplex";
+    }
+    /// IRI tag for [`FaceMap`](super::FaceMap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FaceMap;
+    impl crate::enforcement::has_iri_sealed::Sealed for FaceMap {}
+    impl crate::enforcement::HasIri for FaceMap {
+        const IRI: &'static str = "https://uor.foundation/homology/FaceMap";
+    }
+    /// IRI tag for [`ChainGroup`](super::ChainGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ChainGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for ChainGroup {}
+    impl crate::enforcement::HasIri for ChainGroup {
+        const IRI: &'static str = "https://uor.foundation/homology/ChainGroup";
+    }
+    /// IRI tag for [`BoundaryOperator`](super::BoundaryOperator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundaryOperator;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundaryOperator {}
+    impl crate::enforcement::HasIri for BoundaryOperator {
+        const IRI: &'static str = "https://uor.foundation/homology/BoundaryOperator";
+    }
+    /// IRI tag for [`ChainComplex`](super::ChainComplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ChainComplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for ChainComplex {}
+    impl crate::enforcement::HasIri for ChainComplex {
+        const IRI: &'static str = "https://uor.foundation/homology/ChainComplex";
+    }
+    /// IRI tag for [`HomologyGroup`](super::HomologyGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HomologyGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for HomologyGroup {}
+    impl crate::enforcement::HasIri for HomologyGroup {
+        const IRI: &'static str = "https://uor.foundation/homology/HomologyGroup";
+    }
+    /// IRI tag for [`NerveFunctor`](super::NerveFunctor).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NerveFunctor;
+    impl crate::enforcement::has_iri_sealed::Sealed for NerveFunctor {}
+    impl crate::enforcement::HasIri for NerveFunctor {
+        const IRI: &'static str = "https://uor.foundation/homology/NerveFunctor";
+    }
+    /// IRI tag for [`ChainFunctor`](super::ChainFunctor).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ChainFunctor;
+    impl crate::enforcement::has_iri_sealed::Sealed for ChainFunctor {}
+    impl crate::enforcement::HasIri for ChainFunctor {
+        const IRI: &'static str = "https://uor.foundation/homology/ChainFunctor";
+    }
+    /// IRI tag for [`KanComplex`](super::KanComplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct KanComplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for KanComplex {}
+    impl crate::enforcement::HasIri for KanComplex {
+        const IRI: &'static str = "https://uor.foundation/homology/KanComplex";
+    }
+    /// IRI tag for [`HornFiller`](super::HornFiller).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HornFiller;
+    impl crate::enforcement::has_iri_sealed::Sealed for HornFiller {}
+    impl crate::enforcement::HasIri for HornFiller {
+        const IRI: &'static str = "https://uor.foundation/homology/HornFiller";
+    }
+    /// IRI tag for [`PostnikovTruncation`](super::PostnikovTruncation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PostnikovTruncation;
+    impl crate::enforcement::has_iri_sealed::Sealed for PostnikovTruncation {}
+    impl crate::enforcement::HasIri for PostnikovTruncation {
+        const IRI: &'static str = "https://uor.foundation/homology/PostnikovTruncation";
+    }
+    /// IRI tag for [`KInvariant`](super::KInvariant).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct KInvariant;
+    impl crate::enforcement::has_iri_sealed::Sealed for KInvariant {}
+    impl crate::enforcement::HasIri for KInvariant {
+        const IRI: &'static str = "https://uor.foundation/homology/KInvariant";
+    }
+    /// IRI tag for [`DeformationComplex`](super::DeformationComplex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DeformationComplex;
+    impl crate::enforcement::has_iri_sealed::Sealed for DeformationComplex {}
+    impl crate::enforcement::HasIri for DeformationComplex {
+        const IRI: &'static str = "https://uor.foundation/homology/DeformationComplex";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Simplex<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -102,6 +102,74 @@ pub trait InteractionComposition<H: HostTypes> {
     fn reification_depth(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`InteractionContext`](super::InteractionContext).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InteractionContext;
+    impl crate::enforcement::has_iri_sealed::Sealed for InteractionContext {}
+    impl crate::enforcement::HasIri for InteractionContext {
+        const IRI: &'static str = "https://uor.foundation/interaction/InteractionContext";
+    }
+    /// IRI tag for [`CommutatorState`](super::CommutatorState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CommutatorState;
+    impl crate::enforcement::has_iri_sealed::Sealed for CommutatorState {}
+    impl crate::enforcement::HasIri for CommutatorState {
+        const IRI: &'static str = "https://uor.foundation/interaction/CommutatorState";
+    }
+    /// IRI tag for [`AssociatorState`](super::AssociatorState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AssociatorState;
+    impl crate::enforcement::has_iri_sealed::Sealed for AssociatorState {}
+    impl crate::enforcement::HasIri for AssociatorState {
+        const IRI: &'static str = "https://uor.foundation/interaction/AssociatorState";
+    }
+    /// IRI tag for [`AssociatorTriple`](super::AssociatorTriple).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AssociatorTriple;
+    impl crate::enforcement::has_iri_sealed::Sealed for AssociatorTriple {}
+    impl crate::enforcement::HasIri for AssociatorTriple {
+        const IRI: &'static str = "https://uor.foundation/interaction/AssociatorTriple";
+    }
+    /// IRI tag for [`ThreeWaySite`](super::ThreeWaySite).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ThreeWaySite;
+    impl crate::enforcement::has_iri_sealed::Sealed for ThreeWaySite {}
+    impl crate::enforcement::HasIri for ThreeWaySite {
+        const IRI: &'static str = "https://uor.foundation/interaction/ThreeWaySite";
+    }
+    /// IRI tag for [`NegotiationTrace`](super::NegotiationTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NegotiationTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for NegotiationTrace {}
+    impl crate::enforcement::HasIri for NegotiationTrace {
+        const IRI: &'static str = "https://uor.foundation/interaction/NegotiationTrace";
+    }
+    /// IRI tag for [`MutualModelTrace`](super::MutualModelTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MutualModelTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for MutualModelTrace {}
+    impl crate::enforcement::HasIri for MutualModelTrace {
+        const IRI: &'static str = "https://uor.foundation/interaction/MutualModelTrace";
+    }
+    /// IRI tag for [`InteractionNerve`](super::InteractionNerve).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InteractionNerve;
+    impl crate::enforcement::has_iri_sealed::Sealed for InteractionNerve {}
+    impl crate::enforcement::HasIri for InteractionNerve {
+        const IRI: &'static str = "https://uor.foundation/interaction/InteractionNerve";
+    }
+    /// IRI tag for [`InteractionComposition`](super::InteractionComposition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InteractionComposition;
+    impl crate::enforcement::has_iri_sealed::Sealed for InteractionComposition {}
+    impl crate::enforcement::HasIri for InteractionComposition {
+        const IRI: &'static str = "https://uor.foundation/interaction/InteractionComposition";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `InteractionContext<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -323,6 +323,368 @@ pub trait EulerCharacteristicObservable<H: HostTypes>: Observable<H> {
     fn alternating_sum(&self) -> &Self::TermExpression;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Observable`](super::Observable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Observable;
+    impl crate::enforcement::has_iri_sealed::Sealed for Observable {}
+    impl crate::enforcement::HasIri for Observable {
+        const IRI: &'static str = "https://uor.foundation/observable/Observable";
+    }
+    /// IRI tag for [`StratumObservable`](super::StratumObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StratumObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for StratumObservable {}
+    impl crate::enforcement::HasIri for StratumObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/StratumObservable";
+    }
+    /// IRI tag for [`MetricObservable`](super::MetricObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MetricObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for MetricObservable {}
+    impl crate::enforcement::HasIri for MetricObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/MetricObservable";
+    }
+    /// IRI tag for [`PathObservable`](super::PathObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PathObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for PathObservable {}
+    impl crate::enforcement::HasIri for PathObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/PathObservable";
+    }
+    /// IRI tag for [`ReductionObservable`](super::ReductionObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionObservable {}
+    impl crate::enforcement::HasIri for ReductionObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/ReductionObservable";
+    }
+    /// IRI tag for [`CatastropheObservable`](super::CatastropheObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CatastropheObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for CatastropheObservable {}
+    impl crate::enforcement::HasIri for CatastropheObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/CatastropheObservable";
+    }
+    /// IRI tag for [`CurvatureObservable`](super::CurvatureObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CurvatureObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for CurvatureObservable {}
+    impl crate::enforcement::HasIri for CurvatureObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/CurvatureObservable";
+    }
+    /// IRI tag for [`HolonomyObservable`](super::HolonomyObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HolonomyObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for HolonomyObservable {}
+    impl crate::enforcement::HasIri for HolonomyObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/HolonomyObservable";
+    }
+    /// IRI tag for [`RingMetric`](super::RingMetric).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RingMetric;
+    impl crate::enforcement::has_iri_sealed::Sealed for RingMetric {}
+    impl crate::enforcement::HasIri for RingMetric {
+        const IRI: &'static str = "https://uor.foundation/observable/RingMetric";
+    }
+    /// IRI tag for [`HammingMetric`](super::HammingMetric).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HammingMetric;
+    impl crate::enforcement::has_iri_sealed::Sealed for HammingMetric {}
+    impl crate::enforcement::HasIri for HammingMetric {
+        const IRI: &'static str = "https://uor.foundation/observable/HammingMetric";
+    }
+    /// IRI tag for [`IncompatibilityMetric`](super::IncompatibilityMetric).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IncompatibilityMetric;
+    impl crate::enforcement::has_iri_sealed::Sealed for IncompatibilityMetric {}
+    impl crate::enforcement::HasIri for IncompatibilityMetric {
+        const IRI: &'static str = "https://uor.foundation/observable/IncompatibilityMetric";
+    }
+    /// IRI tag for [`ValueModObservable`](super::ValueModObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ValueModObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for ValueModObservable {}
+    impl crate::enforcement::HasIri for ValueModObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/ValueModObservable";
+    }
+    /// IRI tag for [`GroundingSigma`](super::GroundingSigma).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingSigma;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingSigma {}
+    impl crate::enforcement::HasIri for GroundingSigma {
+        const IRI: &'static str = "https://uor.foundation/observable/GroundingSigma";
+    }
+    /// IRI tag for [`JacobianObservable`](super::JacobianObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct JacobianObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for JacobianObservable {}
+    impl crate::enforcement::HasIri for JacobianObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/JacobianObservable";
+    }
+    /// IRI tag for [`StratumValue`](super::StratumValue).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StratumValue;
+    impl crate::enforcement::has_iri_sealed::Sealed for StratumValue {}
+    impl crate::enforcement::HasIri for StratumValue {
+        const IRI: &'static str = "https://uor.foundation/observable/StratumValue";
+    }
+    /// IRI tag for [`StratumDelta`](super::StratumDelta).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StratumDelta;
+    impl crate::enforcement::has_iri_sealed::Sealed for StratumDelta {}
+    impl crate::enforcement::HasIri for StratumDelta {
+        const IRI: &'static str = "https://uor.foundation/observable/StratumDelta";
+    }
+    /// IRI tag for [`StratumTrajectory`](super::StratumTrajectory).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StratumTrajectory;
+    impl crate::enforcement::has_iri_sealed::Sealed for StratumTrajectory {}
+    impl crate::enforcement::HasIri for StratumTrajectory {
+        const IRI: &'static str = "https://uor.foundation/observable/StratumTrajectory";
+    }
+    /// IRI tag for [`PathLength`](super::PathLength).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PathLength;
+    impl crate::enforcement::has_iri_sealed::Sealed for PathLength {}
+    impl crate::enforcement::HasIri for PathLength {
+        const IRI: &'static str = "https://uor.foundation/observable/PathLength";
+    }
+    /// IRI tag for [`TotalVariation`](super::TotalVariation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TotalVariation;
+    impl crate::enforcement::has_iri_sealed::Sealed for TotalVariation {}
+    impl crate::enforcement::HasIri for TotalVariation {
+        const IRI: &'static str = "https://uor.foundation/observable/TotalVariation";
+    }
+    /// IRI tag for [`WindingNumber`](super::WindingNumber).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WindingNumber;
+    impl crate::enforcement::has_iri_sealed::Sealed for WindingNumber {}
+    impl crate::enforcement::HasIri for WindingNumber {
+        const IRI: &'static str = "https://uor.foundation/observable/WindingNumber";
+    }
+    /// IRI tag for [`ReductionLength`](super::ReductionLength).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionLength;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionLength {}
+    impl crate::enforcement::HasIri for ReductionLength {
+        const IRI: &'static str = "https://uor.foundation/observable/ReductionLength";
+    }
+    /// IRI tag for [`ReductionCount`](super::ReductionCount).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionCount;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionCount {}
+    impl crate::enforcement::HasIri for ReductionCount {
+        const IRI: &'static str = "https://uor.foundation/observable/ReductionCount";
+    }
+    /// IRI tag for [`CatastropheThreshold`](super::CatastropheThreshold).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CatastropheThreshold;
+    impl crate::enforcement::has_iri_sealed::Sealed for CatastropheThreshold {}
+    impl crate::enforcement::HasIri for CatastropheThreshold {
+        const IRI: &'static str = "https://uor.foundation/observable/CatastropheThreshold";
+    }
+    /// IRI tag for [`CatastropheCount`](super::CatastropheCount).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CatastropheCount;
+    impl crate::enforcement::has_iri_sealed::Sealed for CatastropheCount {}
+    impl crate::enforcement::HasIri for CatastropheCount {
+        const IRI: &'static str = "https://uor.foundation/observable/CatastropheCount";
+    }
+    /// IRI tag for [`Commutator`](super::Commutator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Commutator;
+    impl crate::enforcement::has_iri_sealed::Sealed for Commutator {}
+    impl crate::enforcement::HasIri for Commutator {
+        const IRI: &'static str = "https://uor.foundation/observable/Commutator";
+    }
+    /// IRI tag for [`CurvatureFlux`](super::CurvatureFlux).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CurvatureFlux;
+    impl crate::enforcement::has_iri_sealed::Sealed for CurvatureFlux {}
+    impl crate::enforcement::HasIri for CurvatureFlux {
+        const IRI: &'static str = "https://uor.foundation/observable/CurvatureFlux";
+    }
+    /// IRI tag for [`Monodromy`](super::Monodromy).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Monodromy;
+    impl crate::enforcement::has_iri_sealed::Sealed for Monodromy {}
+    impl crate::enforcement::HasIri for Monodromy {
+        const IRI: &'static str = "https://uor.foundation/observable/Monodromy";
+    }
+    /// IRI tag for [`ParallelTransport`](super::ParallelTransport).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParallelTransport;
+    impl crate::enforcement::has_iri_sealed::Sealed for ParallelTransport {}
+    impl crate::enforcement::HasIri for ParallelTransport {
+        const IRI: &'static str = "https://uor.foundation/observable/ParallelTransport";
+    }
+    /// IRI tag for [`DihedralElement`](super::DihedralElement).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DihedralElement;
+    impl crate::enforcement::has_iri_sealed::Sealed for DihedralElement {}
+    impl crate::enforcement::HasIri for DihedralElement {
+        const IRI: &'static str = "https://uor.foundation/observable/DihedralElement";
+    }
+    /// IRI tag for [`Jacobian`](super::Jacobian).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Jacobian;
+    impl crate::enforcement::has_iri_sealed::Sealed for Jacobian {}
+    impl crate::enforcement::HasIri for Jacobian {
+        const IRI: &'static str = "https://uor.foundation/observable/Jacobian";
+    }
+    /// IRI tag for [`TopologicalObservable`](super::TopologicalObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TopologicalObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for TopologicalObservable {}
+    impl crate::enforcement::HasIri for TopologicalObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/TopologicalObservable";
+    }
+    /// IRI tag for [`BettiNumber`](super::BettiNumber).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BettiNumber;
+    impl crate::enforcement::has_iri_sealed::Sealed for BettiNumber {}
+    impl crate::enforcement::HasIri for BettiNumber {
+        const IRI: &'static str = "https://uor.foundation/observable/BettiNumber";
+    }
+    /// IRI tag for [`SpectralGap`](super::SpectralGap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SpectralGap;
+    impl crate::enforcement::has_iri_sealed::Sealed for SpectralGap {}
+    impl crate::enforcement::HasIri for SpectralGap {
+        const IRI: &'static str = "https://uor.foundation/observable/SpectralGap";
+    }
+    /// IRI tag for [`ThermoObservable`](super::ThermoObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ThermoObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for ThermoObservable {}
+    impl crate::enforcement::HasIri for ThermoObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/ThermoObservable";
+    }
+    /// IRI tag for [`ResidualEntropy`](super::ResidualEntropy).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ResidualEntropy;
+    impl crate::enforcement::has_iri_sealed::Sealed for ResidualEntropy {}
+    impl crate::enforcement::HasIri for ResidualEntropy {
+        const IRI: &'static str = "https://uor.foundation/observable/ResidualEntropy";
+    }
+    /// IRI tag for [`LandauerCost`](super::LandauerCost).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LandauerCost;
+    impl crate::enforcement::has_iri_sealed::Sealed for LandauerCost {}
+    impl crate::enforcement::HasIri for LandauerCost {
+        const IRI: &'static str = "https://uor.foundation/observable/LandauerCost";
+    }
+    /// IRI tag for [`LandauerBudget`](super::LandauerBudget).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LandauerBudget;
+    impl crate::enforcement::has_iri_sealed::Sealed for LandauerBudget {}
+    impl crate::enforcement::HasIri for LandauerBudget {
+        const IRI: &'static str = "https://uor.foundation/observable/LandauerBudget";
+    }
+    /// IRI tag for [`ReductionEntropy`](super::ReductionEntropy).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionEntropy;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionEntropy {}
+    impl crate::enforcement::HasIri for ReductionEntropy {
+        const IRI: &'static str = "https://uor.foundation/observable/ReductionEntropy";
+    }
+    /// IRI tag for [`SynthesisSignature`](super::SynthesisSignature).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SynthesisSignature;
+    impl crate::enforcement::has_iri_sealed::Sealed for SynthesisSignature {}
+    impl crate::enforcement::HasIri for SynthesisSignature {
+        const IRI: &'static str = "https://uor.foundation/observable/SynthesisSignature";
+    }
+    /// IRI tag for [`SpectralSequencePage`](super::SpectralSequencePage).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SpectralSequencePage;
+    impl crate::enforcement::has_iri_sealed::Sealed for SpectralSequencePage {}
+    impl crate::enforcement::HasIri for SpectralSequencePage {
+        const IRI: &'static str = "https://uor.foundation/observable/SpectralSequencePage";
+    }
+    /// IRI tag for [`LiftObstructionClass`](super::LiftObstructionClass).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftObstructionClass;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftObstructionClass {}
+    impl crate::enforcement::HasIri for LiftObstructionClass {
+        const IRI: &'static str = "https://uor.foundation/observable/LiftObstructionClass";
+    }
+    /// IRI tag for [`MonodromyClass`](super::MonodromyClass).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MonodromyClass;
+    impl crate::enforcement::has_iri_sealed::Sealed for MonodromyClass {}
+    impl crate::enforcement::HasIri for MonodromyClass {
+        const IRI: &'static str = "https://uor.foundation/observable/MonodromyClass";
+    }
+    /// IRI tag for [`HolonomyGroup`](super::HolonomyGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HolonomyGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for HolonomyGroup {}
+    impl crate::enforcement::HasIri for HolonomyGroup {
+        const IRI: &'static str = "https://uor.foundation/observable/HolonomyGroup";
+    }
+    /// IRI tag for [`ClosedConstraintPath`](super::ClosedConstraintPath).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ClosedConstraintPath;
+    impl crate::enforcement::has_iri_sealed::Sealed for ClosedConstraintPath {}
+    impl crate::enforcement::HasIri for ClosedConstraintPath {
+        const IRI: &'static str = "https://uor.foundation/observable/ClosedConstraintPath";
+    }
+    /// IRI tag for [`HomotopyGroup`](super::HomotopyGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HomotopyGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for HomotopyGroup {}
+    impl crate::enforcement::HasIri for HomotopyGroup {
+        const IRI: &'static str = "https://uor.foundation/observable/HomotopyGroup";
+    }
+    /// IRI tag for [`HigherMonodromy`](super::HigherMonodromy).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HigherMonodromy;
+    impl crate::enforcement::has_iri_sealed::Sealed for HigherMonodromy {}
+    impl crate::enforcement::HasIri for HigherMonodromy {
+        const IRI: &'static str = "https://uor.foundation/observable/HigherMonodromy";
+    }
+    /// IRI tag for [`WhiteheadProduct`](super::WhiteheadProduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WhiteheadProduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for WhiteheadProduct {}
+    impl crate::enforcement::HasIri for WhiteheadProduct {
+        const IRI: &'static str = "https://uor.foundation/observable/WhiteheadProduct";
+    }
+    /// IRI tag for [`StratificationRecord`](super::StratificationRecord).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StratificationRecord;
+    impl crate::enforcement::has_iri_sealed::Sealed for StratificationRecord {}
+    impl crate::enforcement::HasIri for StratificationRecord {
+        const IRI: &'static str = "https://uor.foundation/observable/StratificationRecord";
+    }
+    /// IRI tag for [`BaseMetric`](super::BaseMetric).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BaseMetric;
+    impl crate::enforcement::has_iri_sealed::Sealed for BaseMetric {}
+    impl crate::enforcement::HasIri for BaseMetric {
+        const IRI: &'static str = "https://uor.foundation/observable/BaseMetric";
+    }
+    /// IRI tag for [`GroundingObservable`](super::GroundingObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingObservable {}
+    impl crate::enforcement::HasIri for GroundingObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/GroundingObservable";
+    }
+    /// IRI tag for [`EulerCharacteristicObservable`](super::EulerCharacteristicObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EulerCharacteristicObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for EulerCharacteristicObservable {}
+    impl crate::enforcement::HasIri for EulerCharacteristicObservable {
+        const IRI: &'static str = "https://uor.foundation/observable/EulerCharacteristicObservable";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Observable<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
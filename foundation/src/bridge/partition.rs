@@ -169,6 +169,109 @@ pub trait TagSite<H: HostTypes>: SiteIndex<H> {
 /// Observes the free-rank of the partition associated with a Datum's site context, recording the count of unbound sites at the moment of observation. Used as the bound observable for the siteConstraintKind BoundConstraint.
 pub trait FreeRankObservable<H: HostTypes>: crate::bridge::observable::Observable<H> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Partition`](super::Partition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Partition;
+    impl crate::enforcement::has_iri_sealed::Sealed for Partition {}
+    impl crate::enforcement::HasIri for Partition {
+        const IRI: &'static str = "https://uor.foundation/partition/Partition";
+    }
+    /// IRI tag for [`Component`](super::Component).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Component;
+    impl crate::enforcement::has_iri_sealed::Sealed for Component {}
+    impl crate::enforcement::HasIri for Component {
+        const IRI: &'static str = "https://uor.foundation/partition/Component";
+    }
+    /// IRI tag for [`IrreducibleSet`](super::IrreducibleSet).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IrreducibleSet;
+    impl crate::enforcement::has_iri_sealed::Sealed for IrreducibleSet {}
+    impl crate::enforcement::HasIri for IrreducibleSet {
+        const IRI: &'static str = "https://uor.foundation/partition/IrreducibleSet";
+    }
+    /// IRI tag for [`ReducibleSet`](super::ReducibleSet).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReducibleSet;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReducibleSet {}
+    impl crate::enforcement::HasIri for ReducibleSet {
+        const IRI: &'static str = "https://uor.foundation/partition/ReducibleSet";
+    }
+    /// IRI tag for [`UnitGroup`](super::UnitGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct UnitGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for UnitGroup {}
+    impl crate::enforcement::HasIri for UnitGroup {
+        const IRI: &'static str = "https://uor.foundation/partition/UnitGroup";
+    }
+    /// IRI tag for [`Complement`](super::Complement).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Complement;
+    impl crate::enforcement::has_iri_sealed::Sealed for Complement {}
+    impl crate::enforcement::HasIri for Complement {
+        const IRI: &'static str = "https://uor.foundation/partition/Complement";
+    }
+    /// IRI tag for [`SiteIndex`](super::SiteIndex).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SiteIndex;
+    impl crate::enforcement::has_iri_sealed::Sealed for SiteIndex {}
+    impl crate::enforcement::HasIri for SiteIndex {
+        const IRI: &'static str = "https://uor.foundation/partition/SiteIndex";
+    }
+    /// IRI tag for [`FreeRank`](super::FreeRank).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FreeRank;
+    impl crate::enforcement::has_iri_sealed::Sealed for FreeRank {}
+    impl crate::enforcement::HasIri for FreeRank {
+        const IRI: &'static str = "https://uor.foundation/partition/FreeRank";
+    }
+    /// IRI tag for [`SiteBinding`](super::SiteBinding).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SiteBinding;
+    impl crate::enforcement::has_iri_sealed::Sealed for SiteBinding {}
+    impl crate::enforcement::HasIri for SiteBinding {
+        const IRI: &'static str = "https://uor.foundation/partition/SiteBinding";
+    }
+    /// IRI tag for [`PartitionProduct`](super::PartitionProduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PartitionProduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for PartitionProduct {}
+    impl crate::enforcement::HasIri for PartitionProduct {
+        const IRI: &'static str = "https://uor.foundation/partition/PartitionProduct";
+    }
+    /// IRI tag for [`PartitionCoproduct`](super::PartitionCoproduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PartitionCoproduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for PartitionCoproduct {}
+    impl crate::enforcement::HasIri for PartitionCoproduct {
+        const IRI: &'static str = "https://uor.foundation/partition/PartitionCoproduct";
+    }
+    /// IRI tag for [`CartesianPartitionProduct`](super::CartesianPartitionProduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CartesianPartitionProduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for CartesianPartitionProduct {}
+    impl crate::enforcement::HasIri for CartesianPartitionProduct {
+        const IRI: &'static str = "https://uor.foundation/partition/CartesianPartitionProduct";
+    }
+    /// IRI tag for [`TagSite`](super::TagSite).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TagSite;
+    impl crate::enforcement::has_iri_sealed::Sealed for TagSite {}
+    impl crate::enforcement::HasIri for TagSite {
+        const IRI: &'static str = "https://uor.foundation/partition/TagSite";
+    }
+    /// IRI tag for [`FreeRankObservable`](super::FreeRankObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FreeRankObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for FreeRankObservable {}
+    impl crate::enforcement::HasIri for FreeRankObservable {
+        const IRI: &'static str = "https://uor.foundation/partition/FreeRankObservable";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Component<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -137,6 +137,123 @@ pub trait InhabitanceImpossibilityWitness<H: HostTypes>: ImpossibilityWitness<H>
     fn search_trace(&self) -> &Self::InhabitanceSearchTrace;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Proof`](super::Proof).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Proof;
+    impl crate::enforcement::has_iri_sealed::Sealed for Proof {}
+    impl crate::enforcement::HasIri for Proof {
+        const IRI: &'static str = "https://uor.foundation/proof/Proof";
+    }
+    /// IRI tag for [`CoherenceProof`](super::CoherenceProof).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CoherenceProof;
+    impl crate::enforcement::has_iri_sealed::Sealed for CoherenceProof {}
+    impl crate::enforcement::HasIri for CoherenceProof {
+        const IRI: &'static str = "https://uor.foundation/proof/CoherenceProof";
+    }
+    /// IRI tag for [`ComputationCertificate`](super::ComputationCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationCertificate {}
+    impl crate::enforcement::HasIri for ComputationCertificate {
+        const IRI: &'static str = "https://uor.foundation/proof/ComputationCertificate";
+    }
+    /// IRI tag for [`AxiomaticDerivation`](super::AxiomaticDerivation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AxiomaticDerivation;
+    impl crate::enforcement::has_iri_sealed::Sealed for AxiomaticDerivation {}
+    impl crate::enforcement::HasIri for AxiomaticDerivation {
+        const IRI: &'static str = "https://uor.foundation/proof/AxiomaticDerivation";
+    }
+    /// IRI tag for [`CriticalIdentityProof`](super::CriticalIdentityProof).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CriticalIdentityProof;
+    impl crate::enforcement::has_iri_sealed::Sealed for CriticalIdentityProof {}
+    impl crate::enforcement::HasIri for CriticalIdentityProof {
+        const IRI: &'static str = "https://uor.foundation/proof/CriticalIdentityProof";
+    }
+    /// IRI tag for [`WitnessData`](super::WitnessData).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WitnessData;
+    impl crate::enforcement::has_iri_sealed::Sealed for WitnessData {}
+    impl crate::enforcement::HasIri for WitnessData {
+        const IRI: &'static str = "https://uor.foundation/proof/WitnessData";
+    }
+    /// IRI tag for [`ImpossibilityWitness`](super::ImpossibilityWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ImpossibilityWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for ImpossibilityWitness {}
+    impl crate::enforcement::HasIri for ImpossibilityWitness {
+        const IRI: &'static str = "https://uor.foundation/proof/ImpossibilityWitness";
+    }
+    /// IRI tag for [`MorphospaceRecord`](super::MorphospaceRecord).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MorphospaceRecord;
+    impl crate::enforcement::has_iri_sealed::Sealed for MorphospaceRecord {}
+    impl crate::enforcement::HasIri for MorphospaceRecord {
+        const IRI: &'static str = "https://uor.foundation/proof/MorphospaceRecord";
+    }
+    /// IRI tag for [`MorphospaceBoundary`](super::MorphospaceBoundary).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MorphospaceBoundary;
+    impl crate::enforcement::has_iri_sealed::Sealed for MorphospaceBoundary {}
+    impl crate::enforcement::HasIri for MorphospaceBoundary {
+        const IRI: &'static str = "https://uor.foundation/proof/MorphospaceBoundary";
+    }
+    /// IRI tag for [`InductiveProof`](super::InductiveProof).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InductiveProof;
+    impl crate::enforcement::has_iri_sealed::Sealed for InductiveProof {}
+    impl crate::enforcement::HasIri for InductiveProof {
+        const IRI: &'static str = "https://uor.foundation/proof/InductiveProof";
+    }
+    /// IRI tag for [`DerivationTerm`](super::DerivationTerm).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DerivationTerm;
+    impl crate::enforcement::has_iri_sealed::Sealed for DerivationTerm {}
+    impl crate::enforcement::HasIri for DerivationTerm {
+        const IRI: &'static str = "https://uor.foundation/proof/DerivationTerm";
+    }
+    /// IRI tag for [`TacticApplication`](super::TacticApplication).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TacticApplication;
+    impl crate::enforcement::has_iri_sealed::Sealed for TacticApplication {}
+    impl crate::enforcement::HasIri for TacticApplication {
+        const IRI: &'static str = "https://uor.foundation/proof/TacticApplication";
+    }
+    /// IRI tag for [`LemmaInvocation`](super::LemmaInvocation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LemmaInvocation;
+    impl crate::enforcement::has_iri_sealed::Sealed for LemmaInvocation {}
+    impl crate::enforcement::HasIri for LemmaInvocation {
+        const IRI: &'static str = "https://uor.foundation/proof/LemmaInvocation";
+    }
+    /// IRI tag for [`InductionStep`](super::InductionStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InductionStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for InductionStep {}
+    impl crate::enforcement::HasIri for InductionStep {
+        const IRI: &'static str = "https://uor.foundation/proof/InductionStep";
+    }
+    /// IRI tag for [`ComputationStep`](super::ComputationStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationStep {}
+    impl crate::enforcement::HasIri for ComputationStep {
+        const IRI: &'static str = "https://uor.foundation/proof/ComputationStep";
+    }
+    /// IRI tag for [`InhabitanceImpossibilityWitness`](super::InhabitanceImpossibilityWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceImpossibilityWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceImpossibilityWitness {}
+    impl crate::enforcement::HasIri for InhabitanceImpossibilityWitness {
+        const IRI: &'static str = "https://uor.foundation/proof/InhabitanceImpossibilityWitness";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Proof<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
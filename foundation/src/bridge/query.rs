@@ -63,6 +63,53 @@ pub trait SessionQuery<H: HostTypes>: RelationQuery<H> {
     fn session_membership(&self) -> &Self::Session;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Query`](super::Query).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Query;
+    impl crate::enforcement::has_iri_sealed::Sealed for Query {}
+    impl crate::enforcement::HasIri for Query {
+        const IRI: &'static str = "https://uor.foundation/query/Query";
+    }
+    /// IRI tag for [`CoordinateQuery`](super::CoordinateQuery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CoordinateQuery;
+    impl crate::enforcement::has_iri_sealed::Sealed for CoordinateQuery {}
+    impl crate::enforcement::HasIri for CoordinateQuery {
+        const IRI: &'static str = "https://uor.foundation/query/CoordinateQuery";
+    }
+    /// IRI tag for [`MetricQuery`](super::MetricQuery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MetricQuery;
+    impl crate::enforcement::has_iri_sealed::Sealed for MetricQuery {}
+    impl crate::enforcement::HasIri for MetricQuery {
+        const IRI: &'static str = "https://uor.foundation/query/MetricQuery";
+    }
+    /// IRI tag for [`RepresentationQuery`](super::RepresentationQuery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RepresentationQuery;
+    impl crate::enforcement::has_iri_sealed::Sealed for RepresentationQuery {}
+    impl crate::enforcement::HasIri for RepresentationQuery {
+        const IRI: &'static str = "https://uor.foundation/query/RepresentationQuery";
+    }
+    /// IRI tag for [`RelationQuery`](super::RelationQuery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RelationQuery;
+    impl crate::enforcement::has_iri_sealed::Sealed for RelationQuery {}
+    impl crate::enforcement::HasIri for RelationQuery {
+        const IRI: &'static str = "https://uor.foundation/query/RelationQuery";
+    }
+    /// IRI tag for [`SessionQuery`](super::SessionQuery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SessionQuery;
+    impl crate::enforcement::has_iri_sealed::Sealed for SessionQuery {}
+    impl crate::enforcement::HasIri for SessionQuery {
+        const IRI: &'static str = "https://uor.foundation/query/SessionQuery";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Query<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -279,6 +279,221 @@ pub trait ModuliResolver<H: HostTypes>: Resolver<H> {
     fn moduli_deformation(&self) -> &Self::DeformationComplex;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Resolver`](super::Resolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Resolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for Resolver {}
+    impl crate::enforcement::HasIri for Resolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/Resolver";
+    }
+    /// IRI tag for [`DihedralFactorizationResolver`](super::DihedralFactorizationResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DihedralFactorizationResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for DihedralFactorizationResolver {}
+    impl crate::enforcement::HasIri for DihedralFactorizationResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/DihedralFactorizationResolver";
+    }
+    /// IRI tag for [`CanonicalFormResolver`](super::CanonicalFormResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CanonicalFormResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for CanonicalFormResolver {}
+    impl crate::enforcement::HasIri for CanonicalFormResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/CanonicalFormResolver";
+    }
+    /// IRI tag for [`EvaluationResolver`](super::EvaluationResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EvaluationResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for EvaluationResolver {}
+    impl crate::enforcement::HasIri for EvaluationResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/EvaluationResolver";
+    }
+    /// IRI tag for [`ResolutionState`](super::ResolutionState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ResolutionState;
+    impl crate::enforcement::has_iri_sealed::Sealed for ResolutionState {}
+    impl crate::enforcement::HasIri for ResolutionState {
+        const IRI: &'static str = "https://uor.foundation/resolver/ResolutionState";
+    }
+    /// IRI tag for [`RefinementSuggestion`](super::RefinementSuggestion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RefinementSuggestion;
+    impl crate::enforcement::has_iri_sealed::Sealed for RefinementSuggestion {}
+    impl crate::enforcement::HasIri for RefinementSuggestion {
+        const IRI: &'static str = "https://uor.foundation/resolver/RefinementSuggestion";
+    }
+    /// IRI tag for [`CechNerve`](super::CechNerve).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CechNerve;
+    impl crate::enforcement::has_iri_sealed::Sealed for CechNerve {}
+    impl crate::enforcement::HasIri for CechNerve {
+        const IRI: &'static str = "https://uor.foundation/resolver/CechNerve";
+    }
+    /// IRI tag for [`CompletenessResolver`](super::CompletenessResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompletenessResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompletenessResolver {}
+    impl crate::enforcement::HasIri for CompletenessResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/CompletenessResolver";
+    }
+    /// IRI tag for [`WittLevelResolver`](super::WittLevelResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WittLevelResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for WittLevelResolver {}
+    impl crate::enforcement::HasIri for WittLevelResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/WittLevelResolver";
+    }
+    /// IRI tag for [`SessionResolver`](super::SessionResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SessionResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for SessionResolver {}
+    impl crate::enforcement::HasIri for SessionResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/SessionResolver";
+    }
+    /// IRI tag for [`TypeSynthesisResolver`](super::TypeSynthesisResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeSynthesisResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypeSynthesisResolver {}
+    impl crate::enforcement::HasIri for TypeSynthesisResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/TypeSynthesisResolver";
+    }
+    /// IRI tag for [`ConstraintSearchState`](super::ConstraintSearchState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConstraintSearchState;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConstraintSearchState {}
+    impl crate::enforcement::HasIri for ConstraintSearchState {
+        const IRI: &'static str = "https://uor.foundation/resolver/ConstraintSearchState";
+    }
+    /// IRI tag for [`IncrementalCompletenessResolver`](super::IncrementalCompletenessResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct IncrementalCompletenessResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for IncrementalCompletenessResolver {}
+    impl crate::enforcement::HasIri for IncrementalCompletenessResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/IncrementalCompletenessResolver";
+    }
+    /// IRI tag for [`LiftRefinementSuggestion`](super::LiftRefinementSuggestion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftRefinementSuggestion;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftRefinementSuggestion {}
+    impl crate::enforcement::HasIri for LiftRefinementSuggestion {
+        const IRI: &'static str = "https://uor.foundation/resolver/LiftRefinementSuggestion";
+    }
+    /// IRI tag for [`MonodromyResolver`](super::MonodromyResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MonodromyResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for MonodromyResolver {}
+    impl crate::enforcement::HasIri for MonodromyResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/MonodromyResolver";
+    }
+    /// IRI tag for [`JacobianGuidedResolver`](super::JacobianGuidedResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct JacobianGuidedResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for JacobianGuidedResolver {}
+    impl crate::enforcement::HasIri for JacobianGuidedResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/JacobianGuidedResolver";
+    }
+    /// IRI tag for [`SuperpositionResolver`](super::SuperpositionResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SuperpositionResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for SuperpositionResolver {}
+    impl crate::enforcement::HasIri for SuperpositionResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/SuperpositionResolver";
+    }
+    /// IRI tag for [`GroundingAwareResolver`](super::GroundingAwareResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingAwareResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingAwareResolver {}
+    impl crate::enforcement::HasIri for GroundingAwareResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/GroundingAwareResolver";
+    }
+    /// IRI tag for [`GeodesicValidator`](super::GeodesicValidator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GeodesicValidator;
+    impl crate::enforcement::has_iri_sealed::Sealed for GeodesicValidator {}
+    impl crate::enforcement::HasIri for GeodesicValidator {
+        const IRI: &'static str = "https://uor.foundation/resolver/GeodesicValidator";
+    }
+    /// IRI tag for [`MeasurementResolver`](super::MeasurementResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MeasurementResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for MeasurementResolver {}
+    impl crate::enforcement::HasIri for MeasurementResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/MeasurementResolver";
+    }
+    /// IRI tag for [`TowerCompletenessResolver`](super::TowerCompletenessResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TowerCompletenessResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for TowerCompletenessResolver {}
+    impl crate::enforcement::HasIri for TowerCompletenessResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/TowerCompletenessResolver";
+    }
+    /// IRI tag for [`InhabitanceResolver`](super::InhabitanceResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceResolver {}
+    impl crate::enforcement::HasIri for InhabitanceResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/InhabitanceResolver";
+    }
+    /// IRI tag for [`TwoSatDecider`](super::TwoSatDecider).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TwoSatDecider;
+    impl crate::enforcement::has_iri_sealed::Sealed for TwoSatDecider {}
+    impl crate::enforcement::HasIri for TwoSatDecider {
+        const IRI: &'static str = "https://uor.foundation/resolver/TwoSatDecider";
+    }
+    /// IRI tag for [`HornSatDecider`](super::HornSatDecider).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HornSatDecider;
+    impl crate::enforcement::has_iri_sealed::Sealed for HornSatDecider {}
+    impl crate::enforcement::HasIri for HornSatDecider {
+        const IRI: &'static str = "https://uor.foundation/resolver/HornSatDecider";
+    }
+    /// IRI tag for [`ResidualVerdictResolver`](super::ResidualVerdictResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ResidualVerdictResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for ResidualVerdictResolver {}
+    impl crate::enforcement::HasIri for ResidualVerdictResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/ResidualVerdictResolver";
+    }
+    /// IRI tag for [`MultiplicationResolver`](super::MultiplicationResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MultiplicationResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for MultiplicationResolver {}
+    impl crate::enforcement::HasIri for MultiplicationResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/MultiplicationResolver";
+    }
+    /// IRI tag for [`CertifyMapping`](super::CertifyMapping).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CertifyMapping;
+    impl crate::enforcement::has_iri_sealed::Sealed for CertifyMapping {}
+    impl crate::enforcement::HasIri for CertifyMapping {
+        const IRI: &'static str = "https://uor.foundation/resolver/CertifyMapping";
+    }
+    /// IRI tag for [`ExecutionPolicy`](super::ExecutionPolicy).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ExecutionPolicy;
+    impl crate::enforcement::has_iri_sealed::Sealed for ExecutionPolicy {}
+    impl crate::enforcement::HasIri for ExecutionPolicy {
+        const IRI: &'static str = "https://uor.foundation/resolver/ExecutionPolicy";
+    }
+    /// IRI tag for [`HomotopyResolver`](super::HomotopyResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HomotopyResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for HomotopyResolver {}
+    impl crate::enforcement::HasIri for HomotopyResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/HomotopyResolver";
+    }
+    /// IRI tag for [`ModuliResolver`](super::ModuliResolver).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ModuliResolver;
+    impl crate::enforcement::has_iri_sealed::Sealed for ModuliResolver {}
+    impl crate::enforcement::HasIri for ModuliResolver {
+        const IRI: &'static str = "https://uor.foundation/resolver/ModuliResolver";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Resolver<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -117,6 +117,67 @@ pub trait InhabitanceSearchTrace<H: HostTypes>: ComputationTrace<H> {
     fn checkpoint(&self) -> &[Self::InhabitanceCheckpoint];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`ComputationTrace`](super::ComputationTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationTrace {}
+    impl crate::enforcement::HasIri for ComputationTrace {
+        const IRI: &'static str = "https://uor.foundation/trace/ComputationTrace";
+    }
+    /// IRI tag for [`ComputationStep`](super::ComputationStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationStep {}
+    impl crate::enforcement::HasIri for ComputationStep {
+        const IRI: &'static str = "https://uor.foundation/trace/ComputationStep";
+    }
+    /// IRI tag for [`TraceMetrics`](super::TraceMetrics).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TraceMetrics;
+    impl crate::enforcement::has_iri_sealed::Sealed for TraceMetrics {}
+    impl crate::enforcement::HasIri for TraceMetrics {
+        const IRI: &'static str = "https://uor.foundation/trace/TraceMetrics";
+    }
+    /// IRI tag for [`GeodesicTrace`](super::GeodesicTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GeodesicTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for GeodesicTrace {}
+    impl crate::enforcement::HasIri for GeodesicTrace {
+        const IRI: &'static str = "https://uor.foundation/trace/GeodesicTrace";
+    }
+    /// IRI tag for [`GeodesicViolation`](super::GeodesicViolation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GeodesicViolation;
+    impl crate::enforcement::has_iri_sealed::Sealed for GeodesicViolation {}
+    impl crate::enforcement::HasIri for GeodesicViolation {
+        const IRI: &'static str = "https://uor.foundation/trace/GeodesicViolation";
+    }
+    /// IRI tag for [`MeasurementEvent`](super::MeasurementEvent).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MeasurementEvent;
+    impl crate::enforcement::has_iri_sealed::Sealed for MeasurementEvent {}
+    impl crate::enforcement::HasIri for MeasurementEvent {
+        const IRI: &'static str = "https://uor.foundation/trace/MeasurementEvent";
+    }
+    /// IRI tag for [`MeasurementOutcome`](super::MeasurementOutcome).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MeasurementOutcome;
+    impl crate::enforcement::has_iri_sealed::Sealed for MeasurementOutcome {}
+    impl crate::enforcement::HasIri for MeasurementOutcome {
+        const IRI: &'static str = "https://uor.foundation/trace/MeasurementOutcome";
+    }
+    /// IRI tag for [`InhabitanceSearchTrace`](super::InhabitanceSearchTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InhabitanceSearchTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for InhabitanceSearchTrace {}
+    impl crate::enforcement::HasIri for InhabitanceSearchTrace {
+        const IRI: &'static str = "https://uor.foundation/trace/InhabitanceSearchTrace";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `ComputationTrace<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
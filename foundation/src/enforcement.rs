@@ -4239,6 +4239,156 @@ pub const fn const_ring_eval_unary_w128(op: PrimitiveOp, a: u128) -> u128 {
     }
 }
 
+/// Whether the critical identity `neg(bnot(x)) = succ(x)` holds at `x`.
+/// Evaluated in `Z/256Z` through [`const_ring_eval_unary_w8`], where
+/// `neg(x) = 256 - x mod 256` is the additive inverse and `bnot(x)` flips
+/// all eight bits.
+#[inline]
+#[must_use]
+pub const fn critical_identity_holds(x: u8) -> bool {
+    let lhs = const_ring_eval_unary_w8(
+        PrimitiveOp::Neg,
+        const_ring_eval_unary_w8(PrimitiveOp::Bnot, x),
+    );
+    lhs == const_ring_eval_unary_w8(PrimitiveOp::Succ, x)
+}
+
+/// Whether [`critical_identity_holds`] for all 256 values of `Z/256Z`.
+#[must_use]
+pub const fn verify_critical_identity_all() -> bool {
+    let mut x: u8 = 0;
+    loop {
+        if !critical_identity_holds(x) {
+            return false;
+        }
+        if x == u8::MAX {
+            return true;
+        }
+        x += 1;
+    }
+}
+
+/// An element of `op:D2n` at W8, acting on `Z/256Z` as
+/// `x ↦ rotation + x` (a rotation, `succ^rotation`) or
+/// `x ↦ rotation - x` (a reflection, `succ^rotation ∘ neg`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DihedralElement {
+    rotation: u8,
+    reflection: bool,
+}
+
+impl DihedralElement {
+    /// The rotation component `k` of `succ^k` or `succ^k ∘ neg`.
+    #[inline]
+    #[must_use]
+    pub const fn rotation(self) -> u8 {
+        self.rotation
+    }
+
+    /// Whether this element is a reflection (reverses orientation).
+    #[inline]
+    #[must_use]
+    pub const fn is_reflection(self) -> bool {
+        self.reflection
+    }
+
+    /// Applies this element to `x`, evaluating `neg` through
+    /// [`const_ring_eval_unary_w8`].
+    #[inline]
+    #[must_use]
+    pub const fn apply(self, x: u8) -> u8 {
+        let x = if self.reflection {
+            const_ring_eval_unary_w8(PrimitiveOp::Neg, x)
+        } else {
+            x
+        };
+        x.wrapping_add(self.rotation)
+    }
+}
+
+/// `op:DihedralGroup` at W8: `D_{2^8}` of order 512, generated by the ring
+/// reflection `neg` and the hypercube reflection `bnot`.
+/// Elements are indexed in canonical order: `succ^k` at index `k`, then
+/// `succ^k ∘ neg` at index `256 + k`. [`DihedralGroup::compose`] follows the
+/// group law of function composition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DihedralGroup;
+
+impl DihedralGroup {
+    /// Number of elements, `2 · 256`.
+    pub const ORDER: u16 = 512;
+
+    /// The identity element, `succ^0`.
+    pub const IDENTITY: DihedralElement = DihedralElement {
+        rotation: 0,
+        reflection: false,
+    };
+
+    /// The element at `index` in canonical order, or `None` when
+    /// `index >= ORDER`.
+    #[must_use]
+    pub const fn element(index: u16) -> Option<DihedralElement> {
+        if index >= Self::ORDER {
+            return None;
+        }
+        Some(DihedralElement {
+            rotation: (index % 256) as u8,
+            reflection: index >= 256,
+        })
+    }
+
+    /// The canonical index of `a`; inverse of [`Self::element`].
+    #[must_use]
+    pub const fn index(a: DihedralElement) -> u16 {
+        a.rotation as u16 + if a.reflection { 256 } else { 0 }
+    }
+
+    /// The group element realised by a unary `op`: `Neg`, `Bnot`, `Succ`
+    /// or `Pred`. `None` for binary operations.
+    #[must_use]
+    pub const fn from_op(op: PrimitiveOp) -> Option<DihedralElement> {
+        let (rotation, reflection) = match op {
+            PrimitiveOp::Neg => (0, true),
+            PrimitiveOp::Bnot => (u8::MAX, true),
+            PrimitiveOp::Succ => (1, false),
+            PrimitiveOp::Pred => (u8::MAX, false),
+            _ => return None,
+        };
+        Some(DihedralElement {
+            rotation,
+            reflection,
+        })
+    }
+
+    /// The composition `a ∘ b` (apply `b`, then `a`).
+    #[must_use]
+    pub const fn compose(a: DihedralElement, b: DihedralElement) -> DihedralElement {
+        let shifted = if a.reflection {
+            const_ring_eval_unary_w8(PrimitiveOp::Neg, b.rotation)
+        } else {
+            b.rotation
+        };
+        DihedralElement {
+            rotation: a.rotation.wrapping_add(shifted),
+            reflection: a.reflection != b.reflection,
+        }
+    }
+
+    /// The inverse of `a`: reflections are involutions, and `succ^k`
+    /// inverts to `succ^-k`.
+    #[must_use]
+    pub const fn inverse(a: DihedralElement) -> DihedralElement {
+        if a.reflection {
+            a
+        } else {
+            DihedralElement {
+                rotation: const_ring_eval_unary_w8(PrimitiveOp::Neg, a.rotation),
+                reflection: false,
+            }
+        }
+    }
+}
+
 /// v0.2.2 Phase C.3: foundation-internal generic backing for Witt
 /// levels above W128. Holds an inline `[u64; N]` array with no heap
 /// allocation, no global state, and `const fn` arithmetic throughout.
@@ -5564,6 +5714,25 @@ impl<C: Certificate> Certified<C> {
     }
 }
 
+/// Sealed trait carrying the canonical IRI of an ontology class.
+/// Implemented by the zero-sized tags in each namespace module's `iri`
+/// submodule, e.g. `kernel::schema::iri::Ring`, one per class trait.
+/// Downstream crates cannot implement it because the supertrait is private.
+pub trait HasIri: has_iri_sealed::Sealed {
+    /// The canonical IRI of the ontology class.
+    const IRI: &'static str;
+    /// Returns this tag's `IRI` constant.
+    #[must_use]
+    fn iri() -> &'static str {
+        Self::IRI
+    }
+}
+
+pub(crate) mod has_iri_sealed {
+    /// Private supertrait. Not implementable outside this crate.
+    pub trait Sealed {}
+}
+
 /// v0.2.2 Phase C.4: call-site context consumed by the multiplication
 /// resolver. Carries the stack budget (`linear:stackBudgetBytes`), the
 /// const-eval regime, and the limb count of the operand's `Limbs<N>`
@@ -22,6 +22,18 @@ pub trait Element<H: HostTypes> {
     fn witt_length(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Element`](super::Element).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Element;
+    impl crate::enforcement::has_iri_sealed::Sealed for Element {}
+    impl crate::enforcement::HasIri for Element {
+        const IRI: &'static str = "https://uor.foundation/u/Element";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Element<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
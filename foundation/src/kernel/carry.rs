@@ -64,6 +64,53 @@ pub trait EncodingQuality<H: HostTypes> {
 /// Observes the carry depth of a Datum in the W₂ tower, computed as the maximum carry-chain length in any operation producing it. Used as the bound observable for the carryConstraintKind BoundConstraint.
 pub trait CarryDepthObservable<H: HostTypes>: crate::bridge::observable::Observable<H> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`CarryChain`](super::CarryChain).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CarryChain;
+    impl crate::enforcement::has_iri_sealed::Sealed for CarryChain {}
+    impl crate::enforcement::HasIri for CarryChain {
+        const IRI: &'static str = "https://uor.foundation/carry/CarryChain";
+    }
+    /// IRI tag for [`CarryEvent`](super::CarryEvent).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CarryEvent;
+    impl crate::enforcement::has_iri_sealed::Sealed for CarryEvent {}
+    impl crate::enforcement::HasIri for CarryEvent {
+        const IRI: &'static str = "https://uor.foundation/carry/CarryEvent";
+    }
+    /// IRI tag for [`CarryProfile`](super::CarryProfile).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CarryProfile;
+    impl crate::enforcement::has_iri_sealed::Sealed for CarryProfile {}
+    impl crate::enforcement::HasIri for CarryProfile {
+        const IRI: &'static str = "https://uor.foundation/carry/CarryProfile";
+    }
+    /// IRI tag for [`EncodingConfiguration`](super::EncodingConfiguration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EncodingConfiguration;
+    impl crate::enforcement::has_iri_sealed::Sealed for EncodingConfiguration {}
+    impl crate::enforcement::HasIri for EncodingConfiguration {
+        const IRI: &'static str = "https://uor.foundation/carry/EncodingConfiguration";
+    }
+    /// IRI tag for [`EncodingQuality`](super::EncodingQuality).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EncodingQuality;
+    impl crate::enforcement::has_iri_sealed::Sealed for EncodingQuality {}
+    impl crate::enforcement::HasIri for EncodingQuality {
+        const IRI: &'static str = "https://uor.foundation/carry/EncodingQuality";
+    }
+    /// IRI tag for [`CarryDepthObservable`](super::CarryDepthObservable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CarryDepthObservable;
+    impl crate::enforcement::has_iri_sealed::Sealed for CarryDepthObservable {}
+    impl crate::enforcement::HasIri for CarryDepthObservable {
+        const IRI: &'static str = "https://uor.foundation/carry/CarryDepthObservable";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `CarryChain<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -66,6 +66,46 @@ pub trait AssociativeSubalgebra<H: HostTypes> {
     fn associator_ref(&self) -> &Self::AssociatorTriple;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`ConvergenceLevel`](super::ConvergenceLevel).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConvergenceLevel;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConvergenceLevel {}
+    impl crate::enforcement::HasIri for ConvergenceLevel {
+        const IRI: &'static str = "https://uor.foundation/convergence/ConvergenceLevel";
+    }
+    /// IRI tag for [`HopfFiber`](super::HopfFiber).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HopfFiber;
+    impl crate::enforcement::has_iri_sealed::Sealed for HopfFiber {}
+    impl crate::enforcement::HasIri for HopfFiber {
+        const IRI: &'static str = "https://uor.foundation/convergence/HopfFiber";
+    }
+    /// IRI tag for [`ConvergenceResidual`](super::ConvergenceResidual).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConvergenceResidual;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConvergenceResidual {}
+    impl crate::enforcement::HasIri for ConvergenceResidual {
+        const IRI: &'static str = "https://uor.foundation/convergence/ConvergenceResidual";
+    }
+    /// IRI tag for [`CommutativeSubspace`](super::CommutativeSubspace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CommutativeSubspace;
+    impl crate::enforcement::has_iri_sealed::Sealed for CommutativeSubspace {}
+    impl crate::enforcement::HasIri for CommutativeSubspace {
+        const IRI: &'static str = "https://uor.foundation/convergence/CommutativeSubspace";
+    }
+    /// IRI tag for [`AssociativeSubalgebra`](super::AssociativeSubalgebra).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AssociativeSubalgebra;
+    impl crate::enforcement::has_iri_sealed::Sealed for AssociativeSubalgebra {}
+    impl crate::enforcement::HasIri for AssociativeSubalgebra {
+        const IRI: &'static str = "https://uor.foundation/convergence/AssociativeSubalgebra";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `ConvergenceLevel<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -45,6 +45,46 @@ pub trait AlgebraCommutator<H: HostTypes> {}
 /// The associator \[a,b,c\] = (ab)c − a(bc). Zero for R, C, H; non-zero for O.
 pub trait AlgebraAssociator<H: HostTypes> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`NormedDivisionAlgebra`](super::NormedDivisionAlgebra).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NormedDivisionAlgebra;
+    impl crate::enforcement::has_iri_sealed::Sealed for NormedDivisionAlgebra {}
+    impl crate::enforcement::HasIri for NormedDivisionAlgebra {
+        const IRI: &'static str = "https://uor.foundation/division/NormedDivisionAlgebra";
+    }
+    /// IRI tag for [`CayleyDicksonConstruction`](super::CayleyDicksonConstruction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CayleyDicksonConstruction;
+    impl crate::enforcement::has_iri_sealed::Sealed for CayleyDicksonConstruction {}
+    impl crate::enforcement::HasIri for CayleyDicksonConstruction {
+        const IRI: &'static str = "https://uor.foundation/division/CayleyDicksonConstruction";
+    }
+    /// IRI tag for [`MultiplicationTable`](super::MultiplicationTable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MultiplicationTable;
+    impl crate::enforcement::has_iri_sealed::Sealed for MultiplicationTable {}
+    impl crate::enforcement::HasIri for MultiplicationTable {
+        const IRI: &'static str = "https://uor.foundation/division/MultiplicationTable";
+    }
+    /// IRI tag for [`AlgebraCommutator`](super::AlgebraCommutator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AlgebraCommutator;
+    impl crate::enforcement::has_iri_sealed::Sealed for AlgebraCommutator {}
+    impl crate::enforcement::HasIri for AlgebraCommutator {
+        const IRI: &'static str = "https://uor.foundation/division/AlgebraCommutator";
+    }
+    /// IRI tag for [`AlgebraAssociator`](super::AlgebraAssociator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AlgebraAssociator;
+    impl crate::enforcement::has_iri_sealed::Sealed for AlgebraAssociator {}
+    impl crate::enforcement::HasIri for AlgebraAssociator {
+        const IRI: &'static str = "https://uor.foundation/division/AlgebraAssociator";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `NormedDivisionAlgebra<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
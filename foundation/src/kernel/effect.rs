@@ -79,6 +79,74 @@ pub trait DisjointnessWitness<H: HostTypes> {
     fn disjointness_right(&self) -> &Self::EffectTarget;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Effect`](super::Effect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Effect;
+    impl crate::enforcement::has_iri_sealed::Sealed for Effect {}
+    impl crate::enforcement::HasIri for Effect {
+        const IRI: &'static str = "https://uor.foundation/effect/Effect";
+    }
+    /// IRI tag for [`ReversibleEffect`](super::ReversibleEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReversibleEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReversibleEffect {}
+    impl crate::enforcement::HasIri for ReversibleEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/ReversibleEffect";
+    }
+    /// IRI tag for [`PinningEffect`](super::PinningEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PinningEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for PinningEffect {}
+    impl crate::enforcement::HasIri for PinningEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/PinningEffect";
+    }
+    /// IRI tag for [`UnbindingEffect`](super::UnbindingEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct UnbindingEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for UnbindingEffect {}
+    impl crate::enforcement::HasIri for UnbindingEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/UnbindingEffect";
+    }
+    /// IRI tag for [`PhaseEffect`](super::PhaseEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PhaseEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for PhaseEffect {}
+    impl crate::enforcement::HasIri for PhaseEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/PhaseEffect";
+    }
+    /// IRI tag for [`CompositeEffect`](super::CompositeEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompositeEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompositeEffect {}
+    impl crate::enforcement::HasIri for CompositeEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/CompositeEffect";
+    }
+    /// IRI tag for [`ExternalEffect`](super::ExternalEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ExternalEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for ExternalEffect {}
+    impl crate::enforcement::HasIri for ExternalEffect {
+        const IRI: &'static str = "https://uor.foundation/effect/ExternalEffect";
+    }
+    /// IRI tag for [`EffectTarget`](super::EffectTarget).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EffectTarget;
+    impl crate::enforcement::has_iri_sealed::Sealed for EffectTarget {}
+    impl crate::enforcement::HasIri for EffectTarget {
+        const IRI: &'static str = "https://uor.foundation/effect/EffectTarget";
+    }
+    /// IRI tag for [`DisjointnessWitness`](super::DisjointnessWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DisjointnessWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for DisjointnessWitness {}
+    impl crate::enforcement::HasIri for DisjointnessWitness {
+        const IRI: &'static str = "https://uor.foundation/effect/DisjointnessWitness";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Effect<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
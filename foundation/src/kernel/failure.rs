@@ -87,6 +87,95 @@ pub trait FailurePropagation<H: HostTypes> {
     fn propagation_rule(&self) -> &[Self::FailureReason];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`ComputationResult`](super::ComputationResult).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationResult;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationResult {}
+    impl crate::enforcement::HasIri for ComputationResult {
+        const IRI: &'static str = "https://uor.foundation/failure/ComputationResult";
+    }
+    /// IRI tag for [`Success`](super::Success).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Success;
+    impl crate::enforcement::has_iri_sealed::Sealed for Success {}
+    impl crate::enforcement::HasIri for Success {
+        const IRI: &'static str = "https://uor.foundation/failure/Success";
+    }
+    /// IRI tag for [`Failure`](super::Failure).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Failure;
+    impl crate::enforcement::has_iri_sealed::Sealed for Failure {}
+    impl crate::enforcement::HasIri for Failure {
+        const IRI: &'static str = "https://uor.foundation/failure/Failure";
+    }
+    /// IRI tag for [`FailureReason`](super::FailureReason).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FailureReason;
+    impl crate::enforcement::has_iri_sealed::Sealed for FailureReason {}
+    impl crate::enforcement::HasIri for FailureReason {
+        const IRI: &'static str = "https://uor.foundation/failure/FailureReason";
+    }
+    /// IRI tag for [`GuardFailure`](super::GuardFailure).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GuardFailure;
+    impl crate::enforcement::has_iri_sealed::Sealed for GuardFailure {}
+    impl crate::enforcement::HasIri for GuardFailure {
+        const IRI: &'static str = "https://uor.foundation/failure/GuardFailure";
+    }
+    /// IRI tag for [`ConstraintContradiction`](super::ConstraintContradiction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConstraintContradiction;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConstraintContradiction {}
+    impl crate::enforcement::HasIri for ConstraintContradiction {
+        const IRI: &'static str = "https://uor.foundation/failure/ConstraintContradiction";
+    }
+    /// IRI tag for [`SiteExhaustion`](super::SiteExhaustion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SiteExhaustion;
+    impl crate::enforcement::has_iri_sealed::Sealed for SiteExhaustion {}
+    impl crate::enforcement::HasIri for SiteExhaustion {
+        const IRI: &'static str = "https://uor.foundation/failure/SiteExhaustion";
+    }
+    /// IRI tag for [`LiftObstructionFailure`](super::LiftObstructionFailure).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftObstructionFailure;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftObstructionFailure {}
+    impl crate::enforcement::HasIri for LiftObstructionFailure {
+        const IRI: &'static str = "https://uor.foundation/failure/LiftObstructionFailure";
+    }
+    /// IRI tag for [`PartialComputation`](super::PartialComputation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PartialComputation;
+    impl crate::enforcement::has_iri_sealed::Sealed for PartialComputation {}
+    impl crate::enforcement::HasIri for PartialComputation {
+        const IRI: &'static str = "https://uor.foundation/failure/PartialComputation";
+    }
+    /// IRI tag for [`TotalComputation`](super::TotalComputation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TotalComputation;
+    impl crate::enforcement::has_iri_sealed::Sealed for TotalComputation {}
+    impl crate::enforcement::HasIri for TotalComputation {
+        const IRI: &'static str = "https://uor.foundation/failure/TotalComputation";
+    }
+    /// IRI tag for [`Recovery`](super::Recovery).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Recovery;
+    impl crate::enforcement::has_iri_sealed::Sealed for Recovery {}
+    impl crate::enforcement::HasIri for Recovery {
+        const IRI: &'static str = "https://uor.foundation/failure/Recovery";
+    }
+    /// IRI tag for [`FailurePropagation`](super::FailurePropagation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FailurePropagation;
+    impl crate::enforcement::has_iri_sealed::Sealed for FailurePropagation {}
+    impl crate::enforcement::HasIri for FailurePropagation {
+        const IRI: &'static str = "https://uor.foundation/failure/FailurePropagation";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `ComputationResult<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -53,6 +53,53 @@ pub trait LeaseAllocation<H: HostTypes> {
 /// A site that may be pinned at most once (but need not be pinned). Relaxation of LinearSite for incomplete resolution paths.
 pub trait AffineSite<H: HostTypes>: crate::bridge::partition::SiteIndex<H> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`LinearSite`](super::LinearSite).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LinearSite;
+    impl crate::enforcement::has_iri_sealed::Sealed for LinearSite {}
+    impl crate::enforcement::HasIri for LinearSite {
+        const IRI: &'static str = "https://uor.foundation/linear/LinearSite";
+    }
+    /// IRI tag for [`LinearEffect`](super::LinearEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LinearEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for LinearEffect {}
+    impl crate::enforcement::HasIri for LinearEffect {
+        const IRI: &'static str = "https://uor.foundation/linear/LinearEffect";
+    }
+    /// IRI tag for [`LinearTrace`](super::LinearTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LinearTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for LinearTrace {}
+    impl crate::enforcement::HasIri for LinearTrace {
+        const IRI: &'static str = "https://uor.foundation/linear/LinearTrace";
+    }
+    /// IRI tag for [`LinearBudget`](super::LinearBudget).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LinearBudget;
+    impl crate::enforcement::has_iri_sealed::Sealed for LinearBudget {}
+    impl crate::enforcement::HasIri for LinearBudget {
+        const IRI: &'static str = "https://uor.foundation/linear/LinearBudget";
+    }
+    /// IRI tag for [`LeaseAllocation`](super::LeaseAllocation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeaseAllocation;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeaseAllocation {}
+    impl crate::enforcement::HasIri for LeaseAllocation {
+        const IRI: &'static str = "https://uor.foundation/linear/LeaseAllocation";
+    }
+    /// IRI tag for [`AffineSite`](super::AffineSite).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AffineSite;
+    impl crate::enforcement::has_iri_sealed::Sealed for AffineSite {}
+    impl crate::enforcement::HasIri for AffineSite {
+        const IRI: &'static str = "https://uor.foundation/linear/AffineSite";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `LinearSite<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -44,6 +44,32 @@ pub trait MonoidalAssociator<H: HostTypes> {
     fn associator_witness_ref(&self) -> &Self::Certificate;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`MonoidalProduct`](super::MonoidalProduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MonoidalProduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for MonoidalProduct {}
+    impl crate::enforcement::HasIri for MonoidalProduct {
+        const IRI: &'static str = "https://uor.foundation/monoidal/MonoidalProduct";
+    }
+    /// IRI tag for [`MonoidalUnit`](super::MonoidalUnit).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MonoidalUnit;
+    impl crate::enforcement::has_iri_sealed::Sealed for MonoidalUnit {}
+    impl crate::enforcement::HasIri for MonoidalUnit {
+        const IRI: &'static str = "https://uor.foundation/monoidal/MonoidalUnit";
+    }
+    /// IRI tag for [`MonoidalAssociator`](super::MonoidalAssociator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MonoidalAssociator;
+    impl crate::enforcement::has_iri_sealed::Sealed for MonoidalAssociator {}
+    impl crate::enforcement::HasIri for MonoidalAssociator {
+        const IRI: &'static str = "https://uor.foundation/monoidal/MonoidalAssociator";
+    }
+}
+
 #[doc(hidden)]
 #[doc = "THEORY-DEFERRED \u{2014} not a valid implementation; see [docs/theory_deferred.md]. Exists only to satisfy downstream trait-bound references."]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
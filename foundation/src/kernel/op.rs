@@ -167,6 +167,123 @@ pub trait SessionCompositionOperation<H: HostTypes>: ComposedOperation<H> {
 /// A structured group presentation: generators and relations as typed data rather than prose strings.
 pub trait GroupPresentation<H: HostTypes> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Operation`](super::Operation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Operation;
+    impl crate::enforcement::has_iri_sealed::Sealed for Operation {}
+    impl crate::enforcement::HasIri for Operation {
+        const IRI: &'static str = "https://uor.foundation/op/Operation";
+    }
+    /// IRI tag for [`UnaryOp`](super::UnaryOp).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct UnaryOp;
+    impl crate::enforcement::has_iri_sealed::Sealed for UnaryOp {}
+    impl crate::enforcement::HasIri for UnaryOp {
+        const IRI: &'static str = "https://uor.foundation/op/UnaryOp";
+    }
+    /// IRI tag for [`BinaryOp`](super::BinaryOp).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BinaryOp;
+    impl crate::enforcement::has_iri_sealed::Sealed for BinaryOp {}
+    impl crate::enforcement::HasIri for BinaryOp {
+        const IRI: &'static str = "https://uor.foundation/op/BinaryOp";
+    }
+    /// IRI tag for [`Involution`](super::Involution).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Involution;
+    impl crate::enforcement::has_iri_sealed::Sealed for Involution {}
+    impl crate::enforcement::HasIri for Involution {
+        const IRI: &'static str = "https://uor.foundation/op/Involution";
+    }
+    /// IRI tag for [`Identity`](super::Identity).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Identity;
+    impl crate::enforcement::has_iri_sealed::Sealed for Identity {}
+    impl crate::enforcement::HasIri for Identity {
+        const IRI: &'static str = "https://uor.foundation/op/Identity";
+    }
+    /// IRI tag for [`Group`](super::Group).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Group;
+    impl crate::enforcement::has_iri_sealed::Sealed for Group {}
+    impl crate::enforcement::HasIri for Group {
+        const IRI: &'static str = "https://uor.foundation/op/Group";
+    }
+    /// IRI tag for [`DihedralGroup`](super::DihedralGroup).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DihedralGroup;
+    impl crate::enforcement::has_iri_sealed::Sealed for DihedralGroup {}
+    impl crate::enforcement::HasIri for DihedralGroup {
+        const IRI: &'static str = "https://uor.foundation/op/DihedralGroup";
+    }
+    /// IRI tag for [`WittLevelBinding`](super::WittLevelBinding).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WittLevelBinding;
+    impl crate::enforcement::has_iri_sealed::Sealed for WittLevelBinding {}
+    impl crate::enforcement::HasIri for WittLevelBinding {
+        const IRI: &'static str = "https://uor.foundation/op/WittLevelBinding";
+    }
+    /// IRI tag for [`QuantumThermodynamicDomain`](super::QuantumThermodynamicDomain).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct QuantumThermodynamicDomain;
+    impl crate::enforcement::has_iri_sealed::Sealed for QuantumThermodynamicDomain {}
+    impl crate::enforcement::HasIri for QuantumThermodynamicDomain {
+        const IRI: &'static str = "https://uor.foundation/op/QuantumThermodynamicDomain";
+    }
+    /// IRI tag for [`ComposedOperation`](super::ComposedOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComposedOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComposedOperation {}
+    impl crate::enforcement::HasIri for ComposedOperation {
+        const IRI: &'static str = "https://uor.foundation/op/ComposedOperation";
+    }
+    /// IRI tag for [`DispatchOperation`](super::DispatchOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DispatchOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for DispatchOperation {}
+    impl crate::enforcement::HasIri for DispatchOperation {
+        const IRI: &'static str = "https://uor.foundation/op/DispatchOperation";
+    }
+    /// IRI tag for [`InferenceOperation`](super::InferenceOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InferenceOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for InferenceOperation {}
+    impl crate::enforcement::HasIri for InferenceOperation {
+        const IRI: &'static str = "https://uor.foundation/op/InferenceOperation";
+    }
+    /// IRI tag for [`AccumulationOperation`](super::AccumulationOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AccumulationOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for AccumulationOperation {}
+    impl crate::enforcement::HasIri for AccumulationOperation {
+        const IRI: &'static str = "https://uor.foundation/op/AccumulationOperation";
+    }
+    /// IRI tag for [`LeasePartitionOperation`](super::LeasePartitionOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeasePartitionOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeasePartitionOperation {}
+    impl crate::enforcement::HasIri for LeasePartitionOperation {
+        const IRI: &'static str = "https://uor.foundation/op/LeasePartitionOperation";
+    }
+    /// IRI tag for [`SessionCompositionOperation`](super::SessionCompositionOperation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SessionCompositionOperation;
+    impl crate::enforcement::has_iri_sealed::Sealed for SessionCompositionOperation {}
+    impl crate::enforcement::HasIri for SessionCompositionOperation {
+        const IRI: &'static str = "https://uor.foundation/op/SessionCompositionOperation";
+    }
+    /// IRI tag for [`GroupPresentation`](super::GroupPresentation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroupPresentation;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroupPresentation {}
+    impl crate::enforcement::HasIri for GroupPresentation {
+        const IRI: &'static str = "https://uor.foundation/op/GroupPresentation";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Operation<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
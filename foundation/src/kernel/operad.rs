@@ -32,6 +32,25 @@ pub trait OperadComposition<H: HostTypes> {
     fn composed_grounding(&self) -> &Self::GroundingMap;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`StructuralOperad`](super::StructuralOperad).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StructuralOperad;
+    impl crate::enforcement::has_iri_sealed::Sealed for StructuralOperad {}
+    impl crate::enforcement::HasIri for StructuralOperad {
+        const IRI: &'static str = "https://uor.foundation/operad/StructuralOperad";
+    }
+    /// IRI tag for [`OperadComposition`](super::OperadComposition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct OperadComposition;
+    impl crate::enforcement::has_iri_sealed::Sealed for OperadComposition {}
+    impl crate::enforcement::HasIri for OperadComposition {
+        const IRI: &'static str = "https://uor.foundation/operad/OperadComposition";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `StructuralOperad<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -62,6 +62,46 @@ pub trait SitePartitioning<H: HostTypes> {
     fn component_count(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`ParallelProduct`](super::ParallelProduct).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParallelProduct;
+    impl crate::enforcement::has_iri_sealed::Sealed for ParallelProduct {}
+    impl crate::enforcement::HasIri for ParallelProduct {
+        const IRI: &'static str = "https://uor.foundation/parallel/ParallelProduct";
+    }
+    /// IRI tag for [`DisjointnessCertificate`](super::DisjointnessCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DisjointnessCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for DisjointnessCertificate {}
+    impl crate::enforcement::HasIri for DisjointnessCertificate {
+        const IRI: &'static str = "https://uor.foundation/parallel/DisjointnessCertificate";
+    }
+    /// IRI tag for [`SynchronizationPoint`](super::SynchronizationPoint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SynchronizationPoint;
+    impl crate::enforcement::has_iri_sealed::Sealed for SynchronizationPoint {}
+    impl crate::enforcement::HasIri for SynchronizationPoint {
+        const IRI: &'static str = "https://uor.foundation/parallel/SynchronizationPoint";
+    }
+    /// IRI tag for [`ParallelTrace`](super::ParallelTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ParallelTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for ParallelTrace {}
+    impl crate::enforcement::HasIri for ParallelTrace {
+        const IRI: &'static str = "https://uor.foundation/parallel/ParallelTrace";
+    }
+    /// IRI tag for [`SitePartitioning`](super::SitePartitioning).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SitePartitioning;
+    impl crate::enforcement::has_iri_sealed::Sealed for SitePartitioning {}
+    impl crate::enforcement::HasIri for SitePartitioning {
+        const IRI: &'static str = "https://uor.foundation/parallel/SitePartitioning";
+    }
+}
+
 #[doc(hidden)]
 #[doc = "THEORY-DEFERRED \u{2014} not a valid implementation; see [docs/theory_deferred.md]. Exists only to satisfy downstream trait-bound references."]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
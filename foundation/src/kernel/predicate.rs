@@ -95,6 +95,74 @@ pub trait MatchExpression<H: HostTypes>: crate::kernel::schema::Term<H> {
     fn match_arms(&self) -> &[Self::MatchArm];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Predicate`](super::Predicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Predicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for Predicate {}
+    impl crate::enforcement::HasIri for Predicate {
+        const IRI: &'static str = "https://uor.foundation/predicate/Predicate";
+    }
+    /// IRI tag for [`TypePredicate`](super::TypePredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypePredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypePredicate {}
+    impl crate::enforcement::HasIri for TypePredicate {
+        const IRI: &'static str = "https://uor.foundation/predicate/TypePredicate";
+    }
+    /// IRI tag for [`StatePredicate`](super::StatePredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StatePredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for StatePredicate {}
+    impl crate::enforcement::HasIri for StatePredicate {
+        const IRI: &'static str = "https://uor.foundation/predicate/StatePredicate";
+    }
+    /// IRI tag for [`SitePredicate`](super::SitePredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SitePredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for SitePredicate {}
+    impl crate::enforcement::HasIri for SitePredicate {
+        const IRI: &'static str = "https://uor.foundation/predicate/SitePredicate";
+    }
+    /// IRI tag for [`DispatchRule`](super::DispatchRule).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DispatchRule;
+    impl crate::enforcement::has_iri_sealed::Sealed for DispatchRule {}
+    impl crate::enforcement::HasIri for DispatchRule {
+        const IRI: &'static str = "https://uor.foundation/predicate/DispatchRule";
+    }
+    /// IRI tag for [`DispatchTable`](super::DispatchTable).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DispatchTable;
+    impl crate::enforcement::has_iri_sealed::Sealed for DispatchTable {}
+    impl crate::enforcement::HasIri for DispatchTable {
+        const IRI: &'static str = "https://uor.foundation/predicate/DispatchTable";
+    }
+    /// IRI tag for [`GuardedTransition`](super::GuardedTransition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GuardedTransition;
+    impl crate::enforcement::has_iri_sealed::Sealed for GuardedTransition {}
+    impl crate::enforcement::HasIri for GuardedTransition {
+        const IRI: &'static str = "https://uor.foundation/predicate/GuardedTransition";
+    }
+    /// IRI tag for [`MatchArm`](super::MatchArm).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MatchArm;
+    impl crate::enforcement::has_iri_sealed::Sealed for MatchArm {}
+    impl crate::enforcement::HasIri for MatchArm {
+        const IRI: &'static str = "https://uor.foundation/predicate/MatchArm";
+    }
+    /// IRI tag for [`MatchExpression`](super::MatchExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MatchExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for MatchExpression {}
+    impl crate::enforcement::HasIri for MatchExpression {
+        const IRI: &'static str = "https://uor.foundation/predicate/MatchExpression";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Predicate<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
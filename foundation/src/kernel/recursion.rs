@@ -64,6 +64,60 @@ pub trait RecursionTrace<H: HostTypes>: crate::bridge::trace::ComputationTrace<H
 /// A bounded recursion where the descent measure is the structural size of the input type (site count, constraint count, or operad nesting depth).
 pub trait StructuralRecursion<H: HostTypes>: BoundedRecursion<H> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`BoundedRecursion`](super::BoundedRecursion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundedRecursion;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundedRecursion {}
+    impl crate::enforcement::HasIri for BoundedRecursion {
+        const IRI: &'static str = "https://uor.foundation/recursion/BoundedRecursion";
+    }
+    /// IRI tag for [`DescentMeasure`](super::DescentMeasure).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DescentMeasure;
+    impl crate::enforcement::has_iri_sealed::Sealed for DescentMeasure {}
+    impl crate::enforcement::HasIri for DescentMeasure {
+        const IRI: &'static str = "https://uor.foundation/recursion/DescentMeasure";
+    }
+    /// IRI tag for [`BaseCase`](super::BaseCase).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BaseCase;
+    impl crate::enforcement::has_iri_sealed::Sealed for BaseCase {}
+    impl crate::enforcement::HasIri for BaseCase {
+        const IRI: &'static str = "https://uor.foundation/recursion/BaseCase";
+    }
+    /// IRI tag for [`RecursiveCase`](super::RecursiveCase).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RecursiveCase;
+    impl crate::enforcement::has_iri_sealed::Sealed for RecursiveCase {}
+    impl crate::enforcement::HasIri for RecursiveCase {
+        const IRI: &'static str = "https://uor.foundation/recursion/RecursiveCase";
+    }
+    /// IRI tag for [`RecursiveStep`](super::RecursiveStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RecursiveStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for RecursiveStep {}
+    impl crate::enforcement::HasIri for RecursiveStep {
+        const IRI: &'static str = "https://uor.foundation/recursion/RecursiveStep";
+    }
+    /// IRI tag for [`RecursionTrace`](super::RecursionTrace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RecursionTrace;
+    impl crate::enforcement::has_iri_sealed::Sealed for RecursionTrace {}
+    impl crate::enforcement::HasIri for RecursionTrace {
+        const IRI: &'static str = "https://uor.foundation/recursion/RecursionTrace";
+    }
+    /// IRI tag for [`StructuralRecursion`](super::StructuralRecursion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StructuralRecursion;
+    impl crate::enforcement::has_iri_sealed::Sealed for StructuralRecursion {}
+    impl crate::enforcement::HasIri for StructuralRecursion {
+        const IRI: &'static str = "https://uor.foundation/recursion/StructuralRecursion";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `BoundedRecursion<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
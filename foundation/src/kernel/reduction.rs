@@ -442,6 +442,298 @@ pub trait TimingBound<H: HostTypes> {
     fn runtime_budget_ns(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`EulerReduction`](super::EulerReduction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EulerReduction;
+    impl crate::enforcement::has_iri_sealed::Sealed for EulerReduction {}
+    impl crate::enforcement::HasIri for EulerReduction {
+        const IRI: &'static str = "https://uor.foundation/reduction/EulerReduction";
+    }
+    /// IRI tag for [`PhaseRotationScheduler`](super::PhaseRotationScheduler).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PhaseRotationScheduler;
+    impl crate::enforcement::has_iri_sealed::Sealed for PhaseRotationScheduler {}
+    impl crate::enforcement::HasIri for PhaseRotationScheduler {
+        const IRI: &'static str = "https://uor.foundation/reduction/PhaseRotationScheduler";
+    }
+    /// IRI tag for [`TargetConvergenceAngle`](super::TargetConvergenceAngle).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TargetConvergenceAngle;
+    impl crate::enforcement::has_iri_sealed::Sealed for TargetConvergenceAngle {}
+    impl crate::enforcement::HasIri for TargetConvergenceAngle {
+        const IRI: &'static str = "https://uor.foundation/reduction/TargetConvergenceAngle";
+    }
+    /// IRI tag for [`PhaseGateAttestation`](super::PhaseGateAttestation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PhaseGateAttestation;
+    impl crate::enforcement::has_iri_sealed::Sealed for PhaseGateAttestation {}
+    impl crate::enforcement::HasIri for PhaseGateAttestation {
+        const IRI: &'static str = "https://uor.foundation/reduction/PhaseGateAttestation";
+    }
+    /// IRI tag for [`ComplexConjugateRollback`](super::ComplexConjugateRollback).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComplexConjugateRollback;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComplexConjugateRollback {}
+    impl crate::enforcement::HasIri for ComplexConjugateRollback {
+        const IRI: &'static str = "https://uor.foundation/reduction/ComplexConjugateRollback";
+    }
+    /// IRI tag for [`ReductionStep`](super::ReductionStep).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionStep;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionStep {}
+    impl crate::enforcement::HasIri for ReductionStep {
+        const IRI: &'static str = "https://uor.foundation/reduction/ReductionStep";
+    }
+    /// IRI tag for [`ReductionState`](super::ReductionState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionState;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionState {}
+    impl crate::enforcement::HasIri for ReductionState {
+        const IRI: &'static str = "https://uor.foundation/reduction/ReductionState";
+    }
+    /// IRI tag for [`ReductionRule`](super::ReductionRule).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionRule;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionRule {}
+    impl crate::enforcement::HasIri for ReductionRule {
+        const IRI: &'static str = "https://uor.foundation/reduction/ReductionRule";
+    }
+    /// IRI tag for [`Epoch`](super::Epoch).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Epoch;
+    impl crate::enforcement::has_iri_sealed::Sealed for Epoch {}
+    impl crate::enforcement::HasIri for Epoch {
+        const IRI: &'static str = "https://uor.foundation/reduction/Epoch";
+    }
+    /// IRI tag for [`EpochBoundary`](super::EpochBoundary).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EpochBoundary;
+    impl crate::enforcement::has_iri_sealed::Sealed for EpochBoundary {}
+    impl crate::enforcement::HasIri for EpochBoundary {
+        const IRI: &'static str = "https://uor.foundation/reduction/EpochBoundary";
+    }
+    /// IRI tag for [`PredicateExpression`](super::PredicateExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PredicateExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for PredicateExpression {}
+    impl crate::enforcement::HasIri for PredicateExpression {
+        const IRI: &'static str = "https://uor.foundation/reduction/PredicateExpression";
+    }
+    /// IRI tag for [`GuardExpression`](super::GuardExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GuardExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for GuardExpression {}
+    impl crate::enforcement::HasIri for GuardExpression {
+        const IRI: &'static str = "https://uor.foundation/reduction/GuardExpression";
+    }
+    /// IRI tag for [`TransitionEffect`](super::TransitionEffect).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TransitionEffect;
+    impl crate::enforcement::has_iri_sealed::Sealed for TransitionEffect {}
+    impl crate::enforcement::HasIri for TransitionEffect {
+        const IRI: &'static str = "https://uor.foundation/reduction/TransitionEffect";
+    }
+    /// IRI tag for [`PropertyBind`](super::PropertyBind).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PropertyBind;
+    impl crate::enforcement::has_iri_sealed::Sealed for PropertyBind {}
+    impl crate::enforcement::HasIri for PropertyBind {
+        const IRI: &'static str = "https://uor.foundation/reduction/PropertyBind";
+    }
+    /// IRI tag for [`ReductionAdvance`](super::ReductionAdvance).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionAdvance;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionAdvance {}
+    impl crate::enforcement::HasIri for ReductionAdvance {
+        const IRI: &'static str = "https://uor.foundation/reduction/ReductionAdvance";
+    }
+    /// IRI tag for [`ServiceWindow`](super::ServiceWindow).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ServiceWindow;
+    impl crate::enforcement::has_iri_sealed::Sealed for ServiceWindow {}
+    impl crate::enforcement::HasIri for ServiceWindow {
+        const IRI: &'static str = "https://uor.foundation/reduction/ServiceWindow";
+    }
+    /// IRI tag for [`ReductionTransaction`](super::ReductionTransaction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ReductionTransaction;
+    impl crate::enforcement::has_iri_sealed::Sealed for ReductionTransaction {}
+    impl crate::enforcement::HasIri for ReductionTransaction {
+        const IRI: &'static str = "https://uor.foundation/reduction/ReductionTransaction";
+    }
+    /// IRI tag for [`PipelineSuccess`](super::PipelineSuccess).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PipelineSuccess;
+    impl crate::enforcement::has_iri_sealed::Sealed for PipelineSuccess {}
+    impl crate::enforcement::HasIri for PipelineSuccess {
+        const IRI: &'static str = "https://uor.foundation/reduction/PipelineSuccess";
+    }
+    /// IRI tag for [`PipelineFailureReason`](super::PipelineFailureReason).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PipelineFailureReason;
+    impl crate::enforcement::has_iri_sealed::Sealed for PipelineFailureReason {}
+    impl crate::enforcement::HasIri for PipelineFailureReason {
+        const IRI: &'static str = "https://uor.foundation/reduction/PipelineFailureReason";
+    }
+    /// IRI tag for [`PreflightCheck`](super::PreflightCheck).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PreflightCheck;
+    impl crate::enforcement::has_iri_sealed::Sealed for PreflightCheck {}
+    impl crate::enforcement::HasIri for PreflightCheck {
+        const IRI: &'static str = "https://uor.foundation/reduction/PreflightCheck";
+    }
+    /// IRI tag for [`FeasibilityResult`](super::FeasibilityResult).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FeasibilityResult;
+    impl crate::enforcement::has_iri_sealed::Sealed for FeasibilityResult {}
+    impl crate::enforcement::HasIri for FeasibilityResult {
+        const IRI: &'static str = "https://uor.foundation/reduction/FeasibilityResult";
+    }
+    /// IRI tag for [`LeaseState`](super::LeaseState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeaseState;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeaseState {}
+    impl crate::enforcement::HasIri for LeaseState {
+        const IRI: &'static str = "https://uor.foundation/reduction/LeaseState";
+    }
+    /// IRI tag for [`ManagedLease`](super::ManagedLease).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ManagedLease;
+    impl crate::enforcement::has_iri_sealed::Sealed for ManagedLease {}
+    impl crate::enforcement::HasIri for ManagedLease {
+        const IRI: &'static str = "https://uor.foundation/reduction/ManagedLease";
+    }
+    /// IRI tag for [`LeaseCheckpoint`](super::LeaseCheckpoint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LeaseCheckpoint;
+    impl crate::enforcement::has_iri_sealed::Sealed for LeaseCheckpoint {}
+    impl crate::enforcement::HasIri for LeaseCheckpoint {
+        const IRI: &'static str = "https://uor.foundation/reduction/LeaseCheckpoint";
+    }
+    /// IRI tag for [`BackPressureSignal`](super::BackPressureSignal).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BackPressureSignal;
+    impl crate::enforcement::has_iri_sealed::Sealed for BackPressureSignal {}
+    impl crate::enforcement::HasIri for BackPressureSignal {
+        const IRI: &'static str = "https://uor.foundation/reduction/BackPressureSignal";
+    }
+    /// IRI tag for [`DeferredQuerySet`](super::DeferredQuerySet).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DeferredQuerySet;
+    impl crate::enforcement::has_iri_sealed::Sealed for DeferredQuerySet {}
+    impl crate::enforcement::HasIri for DeferredQuerySet {
+        const IRI: &'static str = "https://uor.foundation/reduction/DeferredQuerySet";
+    }
+    /// IRI tag for [`SubleaseTransfer`](super::SubleaseTransfer).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SubleaseTransfer;
+    impl crate::enforcement::has_iri_sealed::Sealed for SubleaseTransfer {}
+    impl crate::enforcement::HasIri for SubleaseTransfer {
+        const IRI: &'static str = "https://uor.foundation/reduction/SubleaseTransfer";
+    }
+    /// IRI tag for [`ComparisonPredicate`](super::ComparisonPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComparisonPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComparisonPredicate {}
+    impl crate::enforcement::HasIri for ComparisonPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/ComparisonPredicate";
+    }
+    /// IRI tag for [`ConjunctionPredicate`](super::ConjunctionPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConjunctionPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConjunctionPredicate {}
+    impl crate::enforcement::HasIri for ConjunctionPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/ConjunctionPredicate";
+    }
+    /// IRI tag for [`DisjunctionPredicate`](super::DisjunctionPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DisjunctionPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for DisjunctionPredicate {}
+    impl crate::enforcement::HasIri for DisjunctionPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/DisjunctionPredicate";
+    }
+    /// IRI tag for [`NegationPredicate`](super::NegationPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NegationPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for NegationPredicate {}
+    impl crate::enforcement::HasIri for NegationPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/NegationPredicate";
+    }
+    /// IRI tag for [`MembershipPredicate`](super::MembershipPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MembershipPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for MembershipPredicate {}
+    impl crate::enforcement::HasIri for MembershipPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/MembershipPredicate";
+    }
+    /// IRI tag for [`GroundingPredicate`](super::GroundingPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingPredicate {}
+    impl crate::enforcement::HasIri for GroundingPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/GroundingPredicate";
+    }
+    /// IRI tag for [`SiteCoveragePredicate`](super::SiteCoveragePredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SiteCoveragePredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for SiteCoveragePredicate {}
+    impl crate::enforcement::HasIri for SiteCoveragePredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/SiteCoveragePredicate";
+    }
+    /// IRI tag for [`EqualsPredicate`](super::EqualsPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EqualsPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for EqualsPredicate {}
+    impl crate::enforcement::HasIri for EqualsPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/EqualsPredicate";
+    }
+    /// IRI tag for [`NonNullPredicate`](super::NonNullPredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct NonNullPredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for NonNullPredicate {}
+    impl crate::enforcement::HasIri for NonNullPredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/NonNullPredicate";
+    }
+    /// IRI tag for [`QuerySubtypePredicate`](super::QuerySubtypePredicate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct QuerySubtypePredicate;
+    impl crate::enforcement::has_iri_sealed::Sealed for QuerySubtypePredicate {}
+    impl crate::enforcement::HasIri for QuerySubtypePredicate {
+        const IRI: &'static str = "https://uor.foundation/reduction/QuerySubtypePredicate";
+    }
+    /// IRI tag for [`CompileUnit`](super::CompileUnit).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompileUnit;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompileUnit {}
+    impl crate::enforcement::HasIri for CompileUnit {
+        const IRI: &'static str = "https://uor.foundation/reduction/CompileUnit";
+    }
+    /// IRI tag for [`FailureField`](super::FailureField).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FailureField;
+    impl crate::enforcement::has_iri_sealed::Sealed for FailureField {}
+    impl crate::enforcement::HasIri for FailureField {
+        const IRI: &'static str = "https://uor.foundation/reduction/FailureField";
+    }
+    /// IRI tag for [`SatBound`](super::SatBound).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SatBound;
+    impl crate::enforcement::has_iri_sealed::Sealed for SatBound {}
+    impl crate::enforcement::HasIri for SatBound {
+        const IRI: &'static str = "https://uor.foundation/reduction/SatBound";
+    }
+    /// IRI tag for [`TimingBound`](super::TimingBound).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TimingBound;
+    impl crate::enforcement::has_iri_sealed::Sealed for TimingBound {}
+    impl crate::enforcement::HasIri for TimingBound {
+        const IRI: &'static str = "https://uor.foundation/reduction/TimingBound";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `EulerReduction<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
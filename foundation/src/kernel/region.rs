@@ -64,6 +64,46 @@ pub trait RegionAllocation<H: HostTypes> {
     fn allocation_working_set(&self) -> &[Self::WorkingSet];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`AddressRegion`](super::AddressRegion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct AddressRegion;
+    impl crate::enforcement::has_iri_sealed::Sealed for AddressRegion {}
+    impl crate::enforcement::HasIri for AddressRegion {
+        const IRI: &'static str = "https://uor.foundation/region/AddressRegion";
+    }
+    /// IRI tag for [`RegionBound`](super::RegionBound).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RegionBound;
+    impl crate::enforcement::has_iri_sealed::Sealed for RegionBound {}
+    impl crate::enforcement::HasIri for RegionBound {
+        const IRI: &'static str = "https://uor.foundation/region/RegionBound";
+    }
+    /// IRI tag for [`LocalityMetric`](super::LocalityMetric).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LocalityMetric;
+    impl crate::enforcement::has_iri_sealed::Sealed for LocalityMetric {}
+    impl crate::enforcement::HasIri for LocalityMetric {
+        const IRI: &'static str = "https://uor.foundation/region/LocalityMetric";
+    }
+    /// IRI tag for [`WorkingSet`](super::WorkingSet).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WorkingSet;
+    impl crate::enforcement::has_iri_sealed::Sealed for WorkingSet {}
+    impl crate::enforcement::HasIri for WorkingSet {
+        const IRI: &'static str = "https://uor.foundation/region/WorkingSet";
+    }
+    /// IRI tag for [`RegionAllocation`](super::RegionAllocation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct RegionAllocation;
+    impl crate::enforcement::has_iri_sealed::Sealed for RegionAllocation {}
+    impl crate::enforcement::HasIri for RegionAllocation {
+        const IRI: &'static str = "https://uor.foundation/region/RegionAllocation";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `AddressRegion<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -166,6 +166,151 @@ pub trait HostBooleanLiteral<H: HostTypes>: HostValue<H> {
 /// An ordered tuple of values drawn from a type:ConstrainedType's carrier. Serves as the witness form for cert:InhabitanceCertificate when verified is true.
 pub trait ValueTuple<H: HostTypes> {}
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Datum`](super::Datum).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Datum;
+    impl crate::enforcement::has_iri_sealed::Sealed for Datum {}
+    impl crate::enforcement::HasIri for Datum {
+        const IRI: &'static str = "https://uor.foundation/schema/Datum";
+    }
+    /// IRI tag for [`Term`](super::Term).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Term;
+    impl crate::enforcement::has_iri_sealed::Sealed for Term {}
+    impl crate::enforcement::HasIri for Term {
+        const IRI: &'static str = "https://uor.foundation/schema/Term";
+    }
+    /// IRI tag for [`Triad`](super::Triad).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Triad;
+    impl crate::enforcement::has_iri_sealed::Sealed for Triad {}
+    impl crate::enforcement::HasIri for Triad {
+        const IRI: &'static str = "https://uor.foundation/schema/Triad";
+    }
+    /// IRI tag for [`Literal`](super::Literal).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Literal;
+    impl crate::enforcement::has_iri_sealed::Sealed for Literal {}
+    impl crate::enforcement::HasIri for Literal {
+        const IRI: &'static str = "https://uor.foundation/schema/Literal";
+    }
+    /// IRI tag for [`Application`](super::Application).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Application;
+    impl crate::enforcement::has_iri_sealed::Sealed for Application {}
+    impl crate::enforcement::HasIri for Application {
+        const IRI: &'static str = "https://uor.foundation/schema/Application";
+    }
+    /// IRI tag for [`Ring`](super::Ring).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Ring;
+    impl crate::enforcement::has_iri_sealed::Sealed for Ring {}
+    impl crate::enforcement::HasIri for Ring {
+        const IRI: &'static str = "https://uor.foundation/schema/Ring";
+    }
+    /// IRI tag for [`W16Ring`](super::W16Ring).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct W16Ring;
+    impl crate::enforcement::has_iri_sealed::Sealed for W16Ring {}
+    impl crate::enforcement::HasIri for W16Ring {
+        const IRI: &'static str = "https://uor.foundation/schema/W16Ring";
+    }
+    /// IRI tag for [`TermExpression`](super::TermExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TermExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for TermExpression {}
+    impl crate::enforcement::HasIri for TermExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/TermExpression";
+    }
+    /// IRI tag for [`LiteralExpression`](super::LiteralExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiteralExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiteralExpression {}
+    impl crate::enforcement::HasIri for LiteralExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/LiteralExpression";
+    }
+    /// IRI tag for [`ApplicationExpression`](super::ApplicationExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ApplicationExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for ApplicationExpression {}
+    impl crate::enforcement::HasIri for ApplicationExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/ApplicationExpression";
+    }
+    /// IRI tag for [`InfixExpression`](super::InfixExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct InfixExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for InfixExpression {}
+    impl crate::enforcement::HasIri for InfixExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/InfixExpression";
+    }
+    /// IRI tag for [`SetExpression`](super::SetExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SetExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for SetExpression {}
+    impl crate::enforcement::HasIri for SetExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/SetExpression";
+    }
+    /// IRI tag for [`CompositionExpression`](super::CompositionExpression).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompositionExpression;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompositionExpression {}
+    impl crate::enforcement::HasIri for CompositionExpression {
+        const IRI: &'static str = "https://uor.foundation/schema/CompositionExpression";
+    }
+    /// IRI tag for [`ForAllDeclaration`](super::ForAllDeclaration).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ForAllDeclaration;
+    impl crate::enforcement::has_iri_sealed::Sealed for ForAllDeclaration {}
+    impl crate::enforcement::HasIri for ForAllDeclaration {
+        const IRI: &'static str = "https://uor.foundation/schema/ForAllDeclaration";
+    }
+    /// IRI tag for [`VariableBinding`](super::VariableBinding).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct VariableBinding;
+    impl crate::enforcement::has_iri_sealed::Sealed for VariableBinding {}
+    impl crate::enforcement::HasIri for VariableBinding {
+        const IRI: &'static str = "https://uor.foundation/schema/VariableBinding";
+    }
+    /// IRI tag for [`SurfaceSymbol`](super::SurfaceSymbol).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SurfaceSymbol;
+    impl crate::enforcement::has_iri_sealed::Sealed for SurfaceSymbol {}
+    impl crate::enforcement::HasIri for SurfaceSymbol {
+        const IRI: &'static str = "https://uor.foundation/schema/SurfaceSymbol";
+    }
+    /// IRI tag for [`HostValue`](super::HostValue).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HostValue;
+    impl crate::enforcement::has_iri_sealed::Sealed for HostValue {}
+    impl crate::enforcement::HasIri for HostValue {
+        const IRI: &'static str = "https://uor.foundation/schema/HostValue";
+    }
+    /// IRI tag for [`HostStringLiteral`](super::HostStringLiteral).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HostStringLiteral;
+    impl crate::enforcement::has_iri_sealed::Sealed for HostStringLiteral {}
+    impl crate::enforcement::HasIri for HostStringLiteral {
+        const IRI: &'static str = "https://uor.foundation/schema/HostStringLiteral";
+    }
+    /// IRI tag for [`HostBooleanLiteral`](super::HostBooleanLiteral).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HostBooleanLiteral;
+    impl crate::enforcement::has_iri_sealed::Sealed for HostBooleanLiteral {}
+    impl crate::enforcement::HasIri for HostBooleanLiteral {
+        const IRI: &'static str = "https://uor.foundation/schema/HostBooleanLiteral";
+    }
+    /// IRI tag for [`ValueTuple`](super::ValueTuple).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ValueTuple;
+    impl crate::enforcement::has_iri_sealed::Sealed for ValueTuple {}
+    impl crate::enforcement::HasIri for ValueTuple {
+        const IRI: &'static str = "https://uor.foundation/schema/ValueTuple";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Datum<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
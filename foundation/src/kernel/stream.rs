@@ -79,6 +79,53 @@ pub trait Unfold<H: HostTypes> {
     fn unfold_result(&self) -> &Self::ProductiveStream;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`ProductiveStream`](super::ProductiveStream).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ProductiveStream;
+    impl crate::enforcement::has_iri_sealed::Sealed for ProductiveStream {}
+    impl crate::enforcement::HasIri for ProductiveStream {
+        const IRI: &'static str = "https://uor.foundation/stream/ProductiveStream";
+    }
+    /// IRI tag for [`Epoch`](super::Epoch).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Epoch;
+    impl crate::enforcement::has_iri_sealed::Sealed for Epoch {}
+    impl crate::enforcement::HasIri for Epoch {
+        const IRI: &'static str = "https://uor.foundation/stream/Epoch";
+    }
+    /// IRI tag for [`EpochBoundary`](super::EpochBoundary).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct EpochBoundary;
+    impl crate::enforcement::has_iri_sealed::Sealed for EpochBoundary {}
+    impl crate::enforcement::HasIri for EpochBoundary {
+        const IRI: &'static str = "https://uor.foundation/stream/EpochBoundary";
+    }
+    /// IRI tag for [`StreamPrefix`](super::StreamPrefix).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StreamPrefix;
+    impl crate::enforcement::has_iri_sealed::Sealed for StreamPrefix {}
+    impl crate::enforcement::HasIri for StreamPrefix {
+        const IRI: &'static str = "https://uor.foundation/stream/StreamPrefix";
+    }
+    /// IRI tag for [`StreamMorphism`](super::StreamMorphism).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StreamMorphism;
+    impl crate::enforcement::has_iri_sealed::Sealed for StreamMorphism {}
+    impl crate::enforcement::HasIri for StreamMorphism {
+        const IRI: &'static str = "https://uor.foundation/stream/StreamMorphism";
+    }
+    /// IRI tag for [`Unfold`](super::Unfold).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Unfold;
+    impl crate::enforcement::has_iri_sealed::Sealed for Unfold {}
+    impl crate::enforcement::HasIri for Unfold {
+        const IRI: &'static str = "https://uor.foundation/stream/Unfold";
+    }
+}
+
 #[doc(hidden)]
 #[doc = "THEORY-DEFERRED \u{2014} not a valid implementation; see [docs/theory_deferred.md]. Exists only to satisfy downstream trait-bound references."]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
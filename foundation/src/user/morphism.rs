@@ -270,6 +270,151 @@ pub trait SequenceElement<H: HostTypes> {
     fn element_index(&self) -> u64;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Transform`](super::Transform).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Transform;
+    impl crate::enforcement::has_iri_sealed::Sealed for Transform {}
+    impl crate::enforcement::HasIri for Transform {
+        const IRI: &'static str = "https://uor.foundation/morphism/Transform";
+    }
+    /// IRI tag for [`Isometry`](super::Isometry).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Isometry;
+    impl crate::enforcement::has_iri_sealed::Sealed for Isometry {}
+    impl crate::enforcement::HasIri for Isometry {
+        const IRI: &'static str = "https://uor.foundation/morphism/Isometry";
+    }
+    /// IRI tag for [`Embedding`](super::Embedding).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Embedding;
+    impl crate::enforcement::has_iri_sealed::Sealed for Embedding {}
+    impl crate::enforcement::HasIri for Embedding {
+        const IRI: &'static str = "https://uor.foundation/morphism/Embedding";
+    }
+    /// IRI tag for [`Action`](super::Action).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Action;
+    impl crate::enforcement::has_iri_sealed::Sealed for Action {}
+    impl crate::enforcement::HasIri for Action {
+        const IRI: &'static str = "https://uor.foundation/morphism/Action";
+    }
+    /// IRI tag for [`Composition`](super::Composition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Composition;
+    impl crate::enforcement::has_iri_sealed::Sealed for Composition {}
+    impl crate::enforcement::HasIri for Composition {
+        const IRI: &'static str = "https://uor.foundation/morphism/Composition";
+    }
+    /// IRI tag for [`Identity`](super::Identity).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Identity;
+    impl crate::enforcement::has_iri_sealed::Sealed for Identity {}
+    impl crate::enforcement::HasIri for Identity {
+        const IRI: &'static str = "https://uor.foundation/morphism/Identity";
+    }
+    /// IRI tag for [`CompositionLaw`](super::CompositionLaw).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompositionLaw;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompositionLaw {}
+    impl crate::enforcement::HasIri for CompositionLaw {
+        const IRI: &'static str = "https://uor.foundation/morphism/CompositionLaw";
+    }
+    /// IRI tag for [`GroundingMap`](super::GroundingMap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingMap;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingMap {}
+    impl crate::enforcement::HasIri for GroundingMap {
+        const IRI: &'static str = "https://uor.foundation/morphism/GroundingMap";
+    }
+    /// IRI tag for [`ProjectionMap`](super::ProjectionMap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ProjectionMap;
+    impl crate::enforcement::has_iri_sealed::Sealed for ProjectionMap {}
+    impl crate::enforcement::HasIri for ProjectionMap {
+        const IRI: &'static str = "https://uor.foundation/morphism/ProjectionMap";
+    }
+    /// IRI tag for [`GroundingCertificate`](super::GroundingCertificate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingCertificate;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingCertificate {}
+    impl crate::enforcement::HasIri for GroundingCertificate {
+        const IRI: &'static str = "https://uor.foundation/morphism/GroundingCertificate";
+    }
+    /// IRI tag for [`TopologicalDelta`](super::TopologicalDelta).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TopologicalDelta;
+    impl crate::enforcement::has_iri_sealed::Sealed for TopologicalDelta {}
+    impl crate::enforcement::HasIri for TopologicalDelta {
+        const IRI: &'static str = "https://uor.foundation/morphism/TopologicalDelta";
+    }
+    /// IRI tag for [`ComputationDatum`](super::ComputationDatum).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ComputationDatum;
+    impl crate::enforcement::has_iri_sealed::Sealed for ComputationDatum {}
+    impl crate::enforcement::HasIri for ComputationDatum {
+        const IRI: &'static str = "https://uor.foundation/morphism/ComputationDatum";
+    }
+    /// IRI tag for [`ApplicationMorphism`](super::ApplicationMorphism).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ApplicationMorphism;
+    impl crate::enforcement::has_iri_sealed::Sealed for ApplicationMorphism {}
+    impl crate::enforcement::HasIri for ApplicationMorphism {
+        const IRI: &'static str = "https://uor.foundation/morphism/ApplicationMorphism";
+    }
+    /// IRI tag for [`PartialApplication`](super::PartialApplication).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PartialApplication;
+    impl crate::enforcement::has_iri_sealed::Sealed for PartialApplication {}
+    impl crate::enforcement::HasIri for PartialApplication {
+        const IRI: &'static str = "https://uor.foundation/morphism/PartialApplication";
+    }
+    /// IRI tag for [`TransformComposition`](super::TransformComposition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TransformComposition;
+    impl crate::enforcement::has_iri_sealed::Sealed for TransformComposition {}
+    impl crate::enforcement::HasIri for TransformComposition {
+        const IRI: &'static str = "https://uor.foundation/morphism/TransformComposition";
+    }
+    /// IRI tag for [`Witness`](super::Witness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Witness;
+    impl crate::enforcement::has_iri_sealed::Sealed for Witness {}
+    impl crate::enforcement::HasIri for Witness {
+        const IRI: &'static str = "https://uor.foundation/morphism/Witness";
+    }
+    /// IRI tag for [`GroundingWitness`](super::GroundingWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingWitness {}
+    impl crate::enforcement::HasIri for GroundingWitness {
+        const IRI: &'static str = "https://uor.foundation/morphism/GroundingWitness";
+    }
+    /// IRI tag for [`ProjectionWitness`](super::ProjectionWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ProjectionWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for ProjectionWitness {}
+    impl crate::enforcement::HasIri for ProjectionWitness {
+        const IRI: &'static str = "https://uor.foundation/morphism/ProjectionWitness";
+    }
+    /// IRI tag for [`SymbolSequence`](super::SymbolSequence).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SymbolSequence;
+    impl crate::enforcement::has_iri_sealed::Sealed for SymbolSequence {}
+    impl crate::enforcement::HasIri for SymbolSequence {
+        const IRI: &'static str = "https://uor.foundation/morphism/SymbolSequence";
+    }
+    /// IRI tag for [`SequenceElement`](super::SequenceElement).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SequenceElement;
+    impl crate::enforcement::has_iri_sealed::Sealed for SequenceElement {}
+    impl crate::enforcement::HasIri for SequenceElement {
+        const IRI: &'static str = "https://uor.foundation/morphism/SequenceElement";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Transform<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -203,6 +203,102 @@ pub trait SessionComposition<H: HostTypes> {
     fn tower_consistency_verified(&self) -> bool;
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`Context`](super::Context).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Context;
+    impl crate::enforcement::has_iri_sealed::Sealed for Context {}
+    impl crate::enforcement::HasIri for Context {
+        const IRI: &'static str = "https://uor.foundation/state/Context";
+    }
+    /// IRI tag for [`Binding`](super::Binding).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Binding;
+    impl crate::enforcement::has_iri_sealed::Sealed for Binding {}
+    impl crate::enforcement::HasIri for Binding {
+        const IRI: &'static str = "https://uor.foundation/state/Binding";
+    }
+    /// IRI tag for [`Frame`](super::Frame).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Frame;
+    impl crate::enforcement::has_iri_sealed::Sealed for Frame {}
+    impl crate::enforcement::HasIri for Frame {
+        const IRI: &'static str = "https://uor.foundation/state/Frame";
+    }
+    /// IRI tag for [`Transition`](super::Transition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Transition;
+    impl crate::enforcement::has_iri_sealed::Sealed for Transition {}
+    impl crate::enforcement::HasIri for Transition {
+        const IRI: &'static str = "https://uor.foundation/state/Transition";
+    }
+    /// IRI tag for [`Session`](super::Session).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Session;
+    impl crate::enforcement::has_iri_sealed::Sealed for Session {}
+    impl crate::enforcement::HasIri for Session {
+        const IRI: &'static str = "https://uor.foundation/state/Session";
+    }
+    /// IRI tag for [`BindingAccumulator`](super::BindingAccumulator).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BindingAccumulator;
+    impl crate::enforcement::has_iri_sealed::Sealed for BindingAccumulator {}
+    impl crate::enforcement::HasIri for BindingAccumulator {
+        const IRI: &'static str = "https://uor.foundation/state/BindingAccumulator";
+    }
+    /// IRI tag for [`SessionBoundary`](super::SessionBoundary).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SessionBoundary;
+    impl crate::enforcement::has_iri_sealed::Sealed for SessionBoundary {}
+    impl crate::enforcement::HasIri for SessionBoundary {
+        const IRI: &'static str = "https://uor.foundation/state/SessionBoundary";
+    }
+    /// IRI tag for [`GroundedContext`](super::GroundedContext).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundedContext;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundedContext {}
+    impl crate::enforcement::HasIri for GroundedContext {
+        const IRI: &'static str = "https://uor.foundation/state/GroundedContext";
+    }
+    /// IRI tag for [`GroundingWitness`](super::GroundingWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GroundingWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for GroundingWitness {}
+    impl crate::enforcement::HasIri for GroundingWitness {
+        const IRI: &'static str = "https://uor.foundation/state/GroundingWitness";
+    }
+    /// IRI tag for [`DomainGroundingRecord`](super::DomainGroundingRecord).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DomainGroundingRecord;
+    impl crate::enforcement::has_iri_sealed::Sealed for DomainGroundingRecord {}
+    impl crate::enforcement::HasIri for DomainGroundingRecord {
+        const IRI: &'static str = "https://uor.foundation/state/DomainGroundingRecord";
+    }
+    /// IRI tag for [`SharedContext`](super::SharedContext).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SharedContext;
+    impl crate::enforcement::has_iri_sealed::Sealed for SharedContext {}
+    impl crate::enforcement::HasIri for SharedContext {
+        const IRI: &'static str = "https://uor.foundation/state/SharedContext";
+    }
+    /// IRI tag for [`ContextLease`](super::ContextLease).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ContextLease;
+    impl crate::enforcement::has_iri_sealed::Sealed for ContextLease {}
+    impl crate::enforcement::HasIri for ContextLease {
+        const IRI: &'static str = "https://uor.foundation/state/ContextLease";
+    }
+    /// IRI tag for [`SessionComposition`](super::SessionComposition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SessionComposition;
+    impl crate::enforcement::has_iri_sealed::Sealed for SessionComposition {}
+    impl crate::enforcement::HasIri for SessionComposition {
+        const IRI: &'static str = "https://uor.foundation/state/SessionComposition";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `Context<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
@@ -365,6 +365,249 @@ pub trait Conjunction<H: HostTypes>: Constraint<H> {
     fn conjuncts(&self) -> &[Self::BoundConstraint];
 }
 
+/// Zero-sized tags carrying the canonical IRI of each class trait in this
+/// namespace via the sealed [`HasIri`](crate::enforcement::HasIri) trait.
+pub mod iri {
+    /// IRI tag for [`TypeDefinition`](super::TypeDefinition).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeDefinition;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypeDefinition {}
+    impl crate::enforcement::HasIri for TypeDefinition {
+        const IRI: &'static str = "https://uor.foundation/type/TypeDefinition";
+    }
+    /// IRI tag for [`PrimitiveType`](super::PrimitiveType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct PrimitiveType;
+    impl crate::enforcement::has_iri_sealed::Sealed for PrimitiveType {}
+    impl crate::enforcement::HasIri for PrimitiveType {
+        const IRI: &'static str = "https://uor.foundation/type/PrimitiveType";
+    }
+    /// IRI tag for [`ProductType`](super::ProductType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ProductType;
+    impl crate::enforcement::has_iri_sealed::Sealed for ProductType {}
+    impl crate::enforcement::HasIri for ProductType {
+        const IRI: &'static str = "https://uor.foundation/type/ProductType";
+    }
+    /// IRI tag for [`SumType`](super::SumType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SumType;
+    impl crate::enforcement::has_iri_sealed::Sealed for SumType {}
+    impl crate::enforcement::HasIri for SumType {
+        const IRI: &'static str = "https://uor.foundation/type/SumType";
+    }
+    /// IRI tag for [`ConstrainedType`](super::ConstrainedType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConstrainedType;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConstrainedType {}
+    impl crate::enforcement::HasIri for ConstrainedType {
+        const IRI: &'static str = "https://uor.foundation/type/ConstrainedType";
+    }
+    /// IRI tag for [`Constraint`](super::Constraint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Constraint;
+    impl crate::enforcement::has_iri_sealed::Sealed for Constraint {}
+    impl crate::enforcement::HasIri for Constraint {
+        const IRI: &'static str = "https://uor.foundation/type/Constraint";
+    }
+    /// IRI tag for [`CompleteType`](super::CompleteType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompleteType;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompleteType {}
+    impl crate::enforcement::HasIri for CompleteType {
+        const IRI: &'static str = "https://uor.foundation/type/CompleteType";
+    }
+    /// IRI tag for [`CompletenessCandidate`](super::CompletenessCandidate).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompletenessCandidate;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompletenessCandidate {}
+    impl crate::enforcement::HasIri for CompletenessCandidate {
+        const IRI: &'static str = "https://uor.foundation/type/CompletenessCandidate";
+    }
+    /// IRI tag for [`CompletenessWitness`](super::CompletenessWitness).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CompletenessWitness;
+    impl crate::enforcement::has_iri_sealed::Sealed for CompletenessWitness {}
+    impl crate::enforcement::HasIri for CompletenessWitness {
+        const IRI: &'static str = "https://uor.foundation/type/CompletenessWitness";
+    }
+    /// IRI tag for [`TypeSynthesisGoal`](super::TypeSynthesisGoal).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeSynthesisGoal;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypeSynthesisGoal {}
+    impl crate::enforcement::HasIri for TypeSynthesisGoal {
+        const IRI: &'static str = "https://uor.foundation/type/TypeSynthesisGoal";
+    }
+    /// IRI tag for [`TypeSynthesisResult`](super::TypeSynthesisResult).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeSynthesisResult;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypeSynthesisResult {}
+    impl crate::enforcement::HasIri for TypeSynthesisResult {
+        const IRI: &'static str = "https://uor.foundation/type/TypeSynthesisResult";
+    }
+    /// IRI tag for [`SynthesizedType`](super::SynthesizedType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SynthesizedType;
+    impl crate::enforcement::has_iri_sealed::Sealed for SynthesizedType {}
+    impl crate::enforcement::HasIri for SynthesizedType {
+        const IRI: &'static str = "https://uor.foundation/type/SynthesizedType";
+    }
+    /// IRI tag for [`MinimalConstraintBasis`](super::MinimalConstraintBasis).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct MinimalConstraintBasis;
+    impl crate::enforcement::has_iri_sealed::Sealed for MinimalConstraintBasis {}
+    impl crate::enforcement::HasIri for MinimalConstraintBasis {
+        const IRI: &'static str = "https://uor.foundation/type/MinimalConstraintBasis";
+    }
+    /// IRI tag for [`WittLift`](super::WittLift).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct WittLift;
+    impl crate::enforcement::has_iri_sealed::Sealed for WittLift {}
+    impl crate::enforcement::HasIri for WittLift {
+        const IRI: &'static str = "https://uor.foundation/type/WittLift";
+    }
+    /// IRI tag for [`LiftObstruction`](super::LiftObstruction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftObstruction;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftObstruction {}
+    impl crate::enforcement::HasIri for LiftObstruction {
+        const IRI: &'static str = "https://uor.foundation/type/LiftObstruction";
+    }
+    /// IRI tag for [`TwistedType`](super::TwistedType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TwistedType;
+    impl crate::enforcement::has_iri_sealed::Sealed for TwistedType {}
+    impl crate::enforcement::HasIri for TwistedType {
+        const IRI: &'static str = "https://uor.foundation/type/TwistedType";
+    }
+    /// IRI tag for [`FlatType`](super::FlatType).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct FlatType;
+    impl crate::enforcement::has_iri_sealed::Sealed for FlatType {}
+    impl crate::enforcement::HasIri for FlatType {
+        const IRI: &'static str = "https://uor.foundation/type/FlatType";
+    }
+    /// IRI tag for [`SuperposedSiteState`](super::SuperposedSiteState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SuperposedSiteState;
+    impl crate::enforcement::has_iri_sealed::Sealed for SuperposedSiteState {}
+    impl crate::enforcement::HasIri for SuperposedSiteState {
+        const IRI: &'static str = "https://uor.foundation/type/SuperposedSiteState";
+    }
+    /// IRI tag for [`ForbiddenSignature`](super::ForbiddenSignature).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ForbiddenSignature;
+    impl crate::enforcement::has_iri_sealed::Sealed for ForbiddenSignature {}
+    impl crate::enforcement::HasIri for ForbiddenSignature {
+        const IRI: &'static str = "https://uor.foundation/type/ForbiddenSignature";
+    }
+    /// IRI tag for [`CollapsedSiteState`](super::CollapsedSiteState).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct CollapsedSiteState;
+    impl crate::enforcement::has_iri_sealed::Sealed for CollapsedSiteState {}
+    impl crate::enforcement::HasIri for CollapsedSiteState {
+        const IRI: &'static str = "https://uor.foundation/type/CollapsedSiteState";
+    }
+    /// IRI tag for [`LiftChain`](super::LiftChain).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct LiftChain;
+    impl crate::enforcement::has_iri_sealed::Sealed for LiftChain {}
+    impl crate::enforcement::HasIri for LiftChain {
+        const IRI: &'static str = "https://uor.foundation/type/LiftChain";
+    }
+    /// IRI tag for [`ObstructionChain`](super::ObstructionChain).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ObstructionChain;
+    impl crate::enforcement::has_iri_sealed::Sealed for ObstructionChain {}
+    impl crate::enforcement::HasIri for ObstructionChain {
+        const IRI: &'static str = "https://uor.foundation/type/ObstructionChain";
+    }
+    /// IRI tag for [`ModuliSpace`](super::ModuliSpace).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ModuliSpace;
+    impl crate::enforcement::has_iri_sealed::Sealed for ModuliSpace {}
+    impl crate::enforcement::HasIri for ModuliSpace {
+        const IRI: &'static str = "https://uor.foundation/type/ModuliSpace";
+    }
+    /// IRI tag for [`HolonomyStratum`](super::HolonomyStratum).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HolonomyStratum;
+    impl crate::enforcement::has_iri_sealed::Sealed for HolonomyStratum {}
+    impl crate::enforcement::HasIri for HolonomyStratum {
+        const IRI: &'static str = "https://uor.foundation/type/HolonomyStratum";
+    }
+    /// IRI tag for [`DeformationFamily`](super::DeformationFamily).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct DeformationFamily;
+    impl crate::enforcement::has_iri_sealed::Sealed for DeformationFamily {}
+    impl crate::enforcement::HasIri for DeformationFamily {
+        const IRI: &'static str = "https://uor.foundation/type/DeformationFamily";
+    }
+    /// IRI tag for [`VersalDeformation`](super::VersalDeformation).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct VersalDeformation;
+    impl crate::enforcement::has_iri_sealed::Sealed for VersalDeformation {}
+    impl crate::enforcement::HasIri for VersalDeformation {
+        const IRI: &'static str = "https://uor.foundation/type/VersalDeformation";
+    }
+    /// IRI tag for [`ModuliTowerMap`](super::ModuliTowerMap).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ModuliTowerMap;
+    impl crate::enforcement::has_iri_sealed::Sealed for ModuliTowerMap {}
+    impl crate::enforcement::HasIri for ModuliTowerMap {
+        const IRI: &'static str = "https://uor.foundation/type/ModuliTowerMap";
+    }
+    /// IRI tag for [`GaloisConnection`](super::GaloisConnection).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct GaloisConnection;
+    impl crate::enforcement::has_iri_sealed::Sealed for GaloisConnection {}
+    impl crate::enforcement::HasIri for GaloisConnection {
+        const IRI: &'static str = "https://uor.foundation/type/GaloisConnection";
+    }
+    /// IRI tag for [`TypeInclusion`](super::TypeInclusion).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct TypeInclusion;
+    impl crate::enforcement::has_iri_sealed::Sealed for TypeInclusion {}
+    impl crate::enforcement::HasIri for TypeInclusion {
+        const IRI: &'static str = "https://uor.foundation/type/TypeInclusion";
+    }
+    /// IRI tag for [`SubtypingLattice`](super::SubtypingLattice).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct SubtypingLattice;
+    impl crate::enforcement::has_iri_sealed::Sealed for SubtypingLattice {}
+    impl crate::enforcement::HasIri for SubtypingLattice {
+        const IRI: &'static str = "https://uor.foundation/type/SubtypingLattice";
+    }
+    /// IRI tag for [`ConstraintDefaults`](super::ConstraintDefaults).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct ConstraintDefaults;
+    impl crate::enforcement::has_iri_sealed::Sealed for ConstraintDefaults {}
+    impl crate::enforcement::HasIri for ConstraintDefaults {
+        const IRI: &'static str = "https://uor.foundation/type/ConstraintDefaults";
+    }
+    /// IRI tag for [`BoundConstraint`](super::BoundConstraint).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundConstraint;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundConstraint {}
+    impl crate::enforcement::HasIri for BoundConstraint {
+        const IRI: &'static str = "https://uor.foundation/type/BoundConstraint";
+    }
+    /// IRI tag for [`BoundShape`](super::BoundShape).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct BoundShape;
+    impl crate::enforcement::has_iri_sealed::Sealed for BoundShape {}
+    impl crate::enforcement::HasIri for BoundShape {
+        const IRI: &'static str = "https://uor.foundation/type/BoundShape";
+    }
+    /// IRI tag for [`Conjunction`](super::Conjunction).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct Conjunction;
+    impl crate::enforcement::has_iri_sealed::Sealed for Conjunction {}
+    impl crate::enforcement::HasIri for Conjunction {
+        const IRI: &'static str = "https://uor.foundation/type/Conjunction";
+    }
+}
+
 /// Phase 2 (orphan-closure) — resolver-absent default impl of `TypeDefinition<H>`.
 /// Every accessor returns `H::EMPTY_*` sentinels (for scalar / host-typed
 /// returns) or a `'static`-lifetime reference to a sibling `Null*`'s `ABSENT`
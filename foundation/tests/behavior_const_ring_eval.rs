@@ -170,3 +170,14 @@ fn all_ops_are_pure() {
         );
     }
 }
+
+// ─── Critical identity: neg(bnot(x)) = succ(x) ──────────────────────────
+
+#[test]
+fn critical_identity_holds_for_every_byte() {
+    use uor_foundation::enforcement::{critical_identity_holds, verify_critical_identity_all};
+    for x in 0..=u8::MAX {
+        assert!(critical_identity_holds(x), "fails at x = {x}");
+    }
+    assert!(verify_critical_identity_all());
+}
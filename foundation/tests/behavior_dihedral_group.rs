@@ -0,0 +1,100 @@
+//! Behavioral contract for `DihedralGroup` / `DihedralElement`.
+//!
+//! `op:D2n` at W8 has 512 elements in canonical order, composes by the
+//! group law of function composition, and every element has an inverse.
+//! The generators `neg` and `bnot` agree with `const_ring_eval_unary_w8`.
+
+use uor_foundation::enforcement::{const_ring_eval_unary_w8, DihedralElement, DihedralGroup};
+use uor_foundation::PrimitiveOp;
+
+fn elements() -> Vec<DihedralElement> {
+    (0..DihedralGroup::ORDER)
+        .filter_map(DihedralGroup::element)
+        .collect()
+}
+
+#[test]
+fn canonical_order_enumerates_every_element_once() {
+    let all = elements();
+    assert_eq!(all.len(), usize::from(DihedralGroup::ORDER));
+    assert_eq!(DihedralGroup::element(DihedralGroup::ORDER), None);
+    assert_eq!(all[0], DihedralGroup::IDENTITY);
+    for (i, e) in all.iter().enumerate() {
+        assert_eq!(usize::from(DihedralGroup::index(*e)), i);
+        assert_eq!(e.is_reflection(), i >= 256);
+    }
+}
+
+#[test]
+fn composition_is_associative() {
+    let all = elements();
+    for a in &all {
+        for b in &all {
+            let ab = DihedralGroup::compose(*a, *b);
+            for c in all.iter().step_by(17) {
+                assert_eq!(
+                    DihedralGroup::compose(ab, *c),
+                    DihedralGroup::compose(*a, DihedralGroup::compose(*b, *c))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn composition_matches_function_composition() {
+    let all = elements();
+    for a in all.iter().step_by(5) {
+        for b in all.iter().step_by(7) {
+            let ab = DihedralGroup::compose(*a, *b);
+            for x in 0..=u8::MAX {
+                assert_eq!(ab.apply(x), a.apply(b.apply(x)));
+            }
+        }
+    }
+}
+
+#[test]
+fn every_element_has_an_inverse() {
+    for a in elements() {
+        let inv = DihedralGroup::inverse(a);
+        assert_eq!(DihedralGroup::compose(a, inv), DihedralGroup::IDENTITY);
+        assert_eq!(DihedralGroup::compose(inv, a), DihedralGroup::IDENTITY);
+    }
+}
+
+#[test]
+fn identity_composes_trivially() {
+    for a in elements() {
+        assert_eq!(DihedralGroup::compose(DihedralGroup::IDENTITY, a), a);
+        assert_eq!(DihedralGroup::compose(a, DihedralGroup::IDENTITY), a);
+    }
+    assert!((0..=u8::MAX).all(|x| DihedralGroup::IDENTITY.apply(x) == x));
+}
+
+#[test]
+fn generators_match_ring_evaluation() {
+    for op in [
+        PrimitiveOp::Neg,
+        PrimitiveOp::Bnot,
+        PrimitiveOp::Succ,
+        PrimitiveOp::Pred,
+    ] {
+        let e = DihedralGroup::from_op(op);
+        assert!(e.is_some(), "{op:?} is not a D2n element");
+        if let Some(e) = e {
+            for x in 0..=u8::MAX {
+                assert_eq!(e.apply(x), const_ring_eval_unary_w8(op, x), "{op:?}({x})");
+            }
+        }
+    }
+    assert_eq!(DihedralGroup::from_op(PrimitiveOp::Add), None);
+
+    // The critical identity as a group equation: succ = neg ∘ bnot.
+    let neg = DihedralGroup::from_op(PrimitiveOp::Neg);
+    let bnot = DihedralGroup::from_op(PrimitiveOp::Bnot);
+    let succ = DihedralGroup::from_op(PrimitiveOp::Succ);
+    if let (Some(neg), Some(bnot)) = (neg, bnot) {
+        assert_eq!(Some(DihedralGroup::compose(neg, bnot)), succ);
+    }
+}
@@ -0,0 +1,48 @@
+//! Behavioral contract for `HasIri`.
+//!
+//! Class IRIs live on the sealed `HasIri` tags in each namespace's `iri`
+//! submodule, not on the class traits, so the traits stay dyn-compatible.
+//! Each tag's `IRI` must equal the ontology IRI of its class.
+
+use uor_foundation::bridge::partition;
+use uor_foundation::enforcement::HasIri;
+use uor_foundation::kernel::schema::{self, Term};
+use uor_foundation::user::type_;
+use uor_foundation::DefaultHostTypes;
+
+fn iri_of<T: HasIri>() -> &'static str {
+    <T as HasIri>::IRI
+}
+
+#[test]
+fn iri_tags_match_ontology_class_iris() {
+    assert_eq!(
+        iri_of::<schema::iri::Ring>(),
+        "https://uor.foundation/schema/Ring"
+    );
+    assert_eq!(
+        iri_of::<partition::iri::Partition>(),
+        "https://uor.foundation/partition/Partition"
+    );
+    assert_eq!(
+        iri_of::<type_::iri::TypeDefinition>(),
+        "https://uor.foundation/type/TypeDefinition"
+    );
+}
+
+#[test]
+fn iri_accessor_returns_the_constant() {
+    assert_eq!(
+        schema::iri::Literal::iri(),
+        <schema::iri::Literal as HasIri>::IRI
+    );
+    assert_ne!(schema::iri::Literal::iri(), schema::iri::Term::iri());
+}
+
+#[test]
+fn class_traits_are_dyn_compatible() {
+    struct Leaf;
+    impl Term<DefaultHostTypes> for Leaf {}
+    let terms: [&dyn Term<DefaultHostTypes>; 1] = [&Leaf];
+    assert_eq!(terms.len(), 1);
+}
@@ -6,6 +6,7 @@
 
 use std::fmt::Write as FmtWrite;
 
+use uor_ontology::iris;
 use uor_ontology::model::{IndividualValue, Ontology};
 
 use crate::emit::{normalize_lean_comment, LeanFile};
@@ -170,11 +171,11 @@ fn detect_vocabulary_enum(
         None => return,
     };
 
-    let suffix = format!("/{class_name}");
+    let class_iri = iris::iri_for(ns_prefix, class_name);
     let mut variants: Vec<(String, String)> = module
         .individuals
         .iter()
-        .filter(|ind| ind.type_.ends_with(&suffix))
+        .filter(|ind| ind.type_ == class_iri)
         .map(|ind| {
             let name = to_camel_case_variant(local_name(ind.id));
             let c = normalize_lean_comment(ind.comment);
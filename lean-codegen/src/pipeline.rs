@@ -26,6 +26,7 @@
 //!   the real `wittBits` field added in `enforcement.rs` Phase 7e.6.
 
 use crate::emit::LeanFile;
+use uor_ontology::iris;
 use uor_ontology::model::{IndividualValue, Ontology};
 
 /// Generate the complete `UOR/Pipeline.lean` module content.
@@ -125,11 +126,12 @@ fn emit_sat_bound_constants(f: &mut LeanFile, ontology: &Ontology) {
 }
 
 fn find_sat_bound_max_vars(ontology: &Ontology, local: &str) -> Option<u64> {
+    let bound_iri = iris::iri_for("reduction", local);
     ontology
         .namespaces
         .iter()
         .flat_map(|n| n.individuals.iter())
-        .find(|i| i.id.ends_with(&format!("/{local}")))
+        .find(|i| i.id == bound_iri)
         .and_then(|i| {
             i.properties.iter().find_map(|(k, v)| {
                 if *k == "https://uor.foundation/reduction/maxVarCount" {
@@ -196,7 +196,14 @@ pub const SHACL_TESTS: usize = 283;
 /// `docs/orphan-closure/classification_report.md` agrees with
 /// `classify_all` and that the `CLASSIFICATION_*` constants in this
 /// file match the live counts.
-pub const CONFORMANCE_CHECKS: usize = 543;
+/// Website coverage: +1 `website/coverage` class-anchor check — every class
+/// has an `id="class-<local>"` anchor on its namespace page.
+/// OWL 2 DL split: +4 — `ontology/owl` now reports subclass targets,
+/// disjointness, functional properties, domain/range, individual types,
+/// and circular imports as six separate results (previously two).
+/// Critical identity: +1 `rust/critical_identity` — `neg(bnot(x)) = succ(x)`
+/// holds for every byte and matches `op:succ op:composedOf`.
+pub const CONFORMANCE_CHECKS: usize = 549;
 
 /// Number of amendments applied to the base ontology.
 pub const AMENDMENTS: usize = 95;
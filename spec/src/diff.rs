@@ -0,0 +1,109 @@
+//! Structural comparison of two [`Ontology`] values.
+//!
+//! [`Ontology::diff`] reports which classes, properties, and named
+//! individuals were added, removed, or changed between two ontology
+//! versions, keyed by IRI. It backs changelog generation and migration
+//! tooling as amendments land.
+
+use std::collections::BTreeMap;
+
+use crate::model::Ontology;
+
+/// Added, removed, and changed term IRIs of one kind (classes, properties,
+/// or individuals). Each list is sorted by IRI.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TermDiff {
+    /// IRIs present only in the newer ontology.
+    pub added: Vec<&'static str>,
+    /// IRIs present only in the older ontology.
+    pub removed: Vec<&'static str>,
+    /// IRIs present in both whose definitions differ.
+    pub changed: Vec<&'static str>,
+}
+
+impl TermDiff {
+    /// Returns `true` if no term was added, removed, or changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    fn between<T: PartialEq>(
+        old: BTreeMap<&'static str, &T>,
+        new: BTreeMap<&'static str, &T>,
+    ) -> Self {
+        let mut diff = Self::default();
+        for (iri, old_term) in &old {
+            match new.get(iri) {
+                None => diff.removed.push(iri),
+                Some(new_term) if new_term != old_term => diff.changed.push(iri),
+                Some(_) => {}
+            }
+        }
+        diff.added = new
+            .keys()
+            .filter(|iri| !old.contains_key(*iri))
+            .copied()
+            .collect();
+        diff
+    }
+}
+
+/// The differences between two ontology versions, as returned by
+/// [`Ontology::diff`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OntologyDiff {
+    /// Class differences.
+    pub classes: TermDiff,
+    /// Property differences.
+    pub properties: TermDiff,
+    /// Named individual differences.
+    pub individuals: TermDiff,
+}
+
+impl OntologyDiff {
+    /// Returns `true` if the two ontologies define identical terms.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.properties.is_empty() && self.individuals.is_empty()
+    }
+}
+
+impl Ontology {
+    /// Compares `self` (the older version) against `other` (the newer one).
+    ///
+    /// Terms are matched by IRI across all namespaces, so a term that moves
+    /// between namespace modules without changing is not reported.
+    #[must_use]
+    pub fn diff(&self, other: &Ontology) -> OntologyDiff {
+        OntologyDiff {
+            classes: TermDiff::between(self.class_map(), other.class_map()),
+            properties: TermDiff::between(self.property_map(), other.property_map()),
+            individuals: TermDiff::between(self.individual_map(), other.individual_map()),
+        }
+    }
+
+    fn class_map(&self) -> BTreeMap<&'static str, &crate::Class> {
+        self.namespaces
+            .iter()
+            .flat_map(|m| m.classes.iter())
+            .map(|c| (c.id, c))
+            .collect()
+    }
+
+    fn property_map(&self) -> BTreeMap<&'static str, &crate::Property> {
+        self.namespaces
+            .iter()
+            .flat_map(|m| m.properties.iter())
+            .map(|p| (p.id, p))
+            .collect()
+    }
+
+    fn individual_map(&self) -> BTreeMap<&'static str, &crate::Individual> {
+        self.namespaces
+            .iter()
+            .flat_map(|m| m.individuals.iter())
+            .map(|i| (i.id, i))
+            .collect()
+    }
+}
@@ -43,16 +43,20 @@
 )]
 
 pub mod counts;
+pub mod diff;
 pub mod model;
 pub mod namespaces;
+pub mod references;
 #[cfg(feature = "serializers")]
 pub mod serializer;
 
+pub use diff::{OntologyDiff, TermDiff};
 pub use model::iris;
 pub use model::{
     AnnotationProperty, Class, Individual, IndividualValue, Namespace, NamespaceModule, Ontology,
     Property, PropertyKind, Space,
 };
+pub use references::ReferenceError;
 
 impl Ontology {
     /// Returns the complete UOR Foundation ontology with all namespaces
@@ -116,11 +120,42 @@ impl Ontology {
             .find(|m| m.namespace.prefix == prefix)
     }
 
+    /// Iterates over the namespace modules classified in `space`, in
+    /// assembly order.
+    pub fn namespaces_in_space(&self, space: Space) -> impl Iterator<Item = &NamespaceModule> {
+        self.namespaces
+            .iter()
+            .filter(move |m| m.namespace.space == space)
+    }
+
     /// Looks up a namespace module by its full IRI (e.g., `"https://uor.foundation/u/"`).
     #[must_use]
     pub fn find_namespace_by_iri(&self, iri: &str) -> Option<&NamespaceModule> {
         self.namespaces.iter().find(|m| m.namespace.iri == iri)
     }
+
+    /// Splits a UOR term IRI into its namespace prefix and local name
+    /// (e.g., `"https://uor.foundation/schema/Ring"` → `("schema", "Ring")`).
+    ///
+    /// Returns `None` unless the IRI is under [`iris::UOR`], names one of
+    /// the known namespaces, and has a non-empty single-segment local name,
+    /// so typo'd namespaces are caught rather than silently accepted.
+    ///
+    /// The prefix is returned as the namespace's own `&'static str` rather
+    /// than a dedicated enum: the namespace set is data assembled by
+    /// [`Ontology::full`], and an enum would be a second copy of it that
+    /// has to be kept in step by hand.
+    #[must_use]
+    pub fn parse_uor_iri<'a>(&self, iri: &'a str) -> Option<(&'static str, &'a str)> {
+        let (_, local) = iri.strip_prefix(iris::UOR)?.split_once('/')?;
+        if local.is_empty() || local.contains('/') {
+            return None;
+        }
+        // Everything before the local name, e.g. `https://uor.foundation/schema/`.
+        let namespace_iri = &iri[..iri.len() - local.len()];
+        self.find_namespace_by_iri(namespace_iri)
+            .map(|m| (m.namespace.prefix, local))
+    }
 }
 
 #[cfg(test)]
@@ -132,6 +167,193 @@ mod tests {
         assert_eq!(Ontology::full().namespaces.len(), counts::NAMESPACES);
     }
 
+    #[test]
+    fn references_resolve_and_bad_parent_is_reported() {
+        let full = Ontology::full();
+        assert_eq!(full.validate_references(), Vec::new());
+
+        let mut mutated = full.clone();
+        let ring = mutated
+            .namespaces
+            .iter_mut()
+            .flat_map(|m| m.classes.iter_mut())
+            .find(|c| c.id == "https://uor.foundation/schema/Ring");
+        assert!(ring.is_some());
+        if let Some(ring) = ring {
+            ring.subclass_of = &["https://uor.foundation/schema/Rnig"];
+        }
+        assert_eq!(
+            mutated.validate_references(),
+            vec![ReferenceError {
+                source: "https://uor.foundation/schema/Ring",
+                field: "subClassOf",
+                target: "https://uor.foundation/schema/Rnig",
+            }]
+        );
+    }
+
+    #[test]
+    fn references_of_the_wrong_kind_or_unknown_datatype_are_reported() {
+        let mut mutated = Ontology::full().clone();
+        for module in &mut mutated.namespaces {
+            for class in &mut module.classes {
+                if class.id == "https://uor.foundation/schema/Ring" {
+                    class.subclass_of = &["https://uor.foundation/schema/value"];
+                }
+            }
+            for prop in &mut module.properties {
+                if prop.id == "https://uor.foundation/schema/wittLength" {
+                    prop.range = "xsd:strnig";
+                }
+            }
+        }
+        assert_eq!(
+            mutated.validate_references(),
+            vec![
+                ReferenceError {
+                    source: "https://uor.foundation/schema/Ring",
+                    field: "subClassOf",
+                    target: "https://uor.foundation/schema/value",
+                },
+                ReferenceError {
+                    source: "https://uor.foundation/schema/wittLength",
+                    field: "range",
+                    target: "xsd:strnig",
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn properties_of_kind_partition_property_count() {
+        let ontology = Ontology::full();
+        let by_kind: usize = [
+            PropertyKind::Object,
+            PropertyKind::Datatype,
+            PropertyKind::Annotation,
+        ]
+        .into_iter()
+        .map(|kind| ontology.properties_of_kind(kind).count())
+        .sum();
+        assert_eq!(
+            by_kind + ontology.annotation_properties.len(),
+            ontology.property_count()
+        );
+        assert!(ontology
+            .properties_of_kind(PropertyKind::Object)
+            .all(|p| p.kind == PropertyKind::Object));
+    }
+
+    #[test]
+    fn diff_reports_exactly_the_removed_class() {
+        let full = Ontology::full();
+        let mut trimmed = full.clone();
+        let removed = trimmed
+            .namespaces
+            .iter_mut()
+            .find(|m| m.namespace.prefix == "schema")
+            .and_then(|m| {
+                let idx = m.classes.iter().position(|c| c.label == "Ring")?;
+                Some(m.classes.remove(idx).id)
+            });
+        assert_eq!(removed, Some("https://uor.foundation/schema/Ring"));
+
+        let diff = full.diff(&trimmed);
+        assert_eq!(
+            diff.classes.removed,
+            vec!["https://uor.foundation/schema/Ring"]
+        );
+        assert!(diff.classes.added.is_empty());
+        assert!(diff.classes.changed.is_empty());
+        assert!(diff.properties.is_empty());
+        assert!(diff.individuals.is_empty());
+        assert!(full.diff(full).is_empty());
+    }
+
+    #[test]
+    fn iri_for_matches_stored_class_iri() {
+        let ring = Ontology::full()
+            .find_namespace("schema")
+            .and_then(|m| m.classes.iter().find(|c| c.label == "Ring"));
+        assert_eq!(
+            ring.map(|c| c.iri()),
+            Some(iris::iri_for("schema", "Ring").as_str())
+        );
+        assert_eq!(ring.map(|c| c.local_name()), Some("Ring"));
+    }
+
+    #[test]
+    fn list_values_report_length_and_items() {
+        let ontology = Ontology::full();
+        let succ = ontology.find_namespace("op").and_then(|m| {
+            m.individuals
+                .iter()
+                .find(|i| i.id == "https://uor.foundation/op/succ")
+        });
+        let value_of = |prop: &str| {
+            succ.and_then(|i| i.properties.iter().find(|(p, _)| *p == prop))
+                .map(|(_, v)| v)
+        };
+
+        let composed = value_of("https://uor.foundation/op/composedOf");
+        assert_eq!(composed.and_then(|v| v.list_len()), Some(2));
+        assert_eq!(
+            composed.and_then(|v| v.list_get(0)),
+            Some("https://uor.foundation/op/neg")
+        );
+        assert_eq!(
+            composed.and_then(|v| v.list_get(1)),
+            Some("https://uor.foundation/op/bnot")
+        );
+        assert_eq!(composed.and_then(|v| v.list_get(2)), None);
+
+        let character = value_of("https://uor.foundation/op/hasGeometricCharacter");
+        assert!(character.is_some());
+        assert_eq!(character.and_then(|v| v.list_len()), None);
+        assert_eq!(character.and_then(|v| v.list_get(0)), None);
+        assert_eq!(IndividualValue::Int(3).list_len(), None);
+    }
+
+    #[test]
+    fn namespaces_in_space_partitions_the_ontology() {
+        let ontology = Ontology::full();
+        let kernel: Vec<&str> = ontology
+            .namespaces_in_space(Space::Kernel)
+            .map(|m| m.namespace.prefix)
+            .collect();
+        assert_eq!(kernel.len(), counts::KERNEL_NAMESPACES);
+        assert_eq!(&kernel[..3], ["u", "schema", "op"]);
+        assert_eq!(
+            ontology.namespaces_in_space(Space::Bridge).count(),
+            counts::BRIDGE_NAMESPACES
+        );
+        assert_eq!(
+            ontology.namespaces_in_space(Space::User).count(),
+            counts::USER_NAMESPACES
+        );
+    }
+
+    #[test]
+    fn parse_uor_iri_accepts_known_namespaces_only() {
+        let ontology = Ontology::full();
+        assert_eq!(
+            ontology.parse_uor_iri("https://uor.foundation/schema/Ring"),
+            Some(("schema", "Ring"))
+        );
+        assert_eq!(
+            ontology.parse_uor_iri("https://uor.foundation/shcema/Ring"),
+            None
+        );
+        assert_eq!(
+            ontology.parse_uor_iri("https://uor.foundation/schema/"),
+            None
+        );
+        assert_eq!(
+            ontology.parse_uor_iri("https://example.org/schema/Ring"),
+            None
+        );
+    }
+
     #[test]
     fn class_count() {
         let total: usize = Ontology::full()
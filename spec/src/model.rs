@@ -80,6 +80,21 @@ pub struct Class {
     pub disjoint_with: &'static [&'static str],
 }
 
+impl Class {
+    /// Returns the class's full IRI (its stored `id`).
+    #[must_use]
+    pub fn iri(&self) -> &'static str {
+        self.id
+    }
+
+    /// Returns the local name: the IRI suffix after the last `/` or `#`
+    /// (e.g., `"Element"` for `"https://uor.foundation/u/Element"`).
+    #[must_use]
+    pub fn local_name(&self) -> &'static str {
+        self.id.rsplit(['/', '#']).next().unwrap_or(self.id)
+    }
+}
+
 impl fmt::Display for Class {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} <{}>", self.label, self.id)
@@ -178,6 +193,28 @@ pub enum IndividualValue {
 // we always key by property IRI instead. The trait bounds are
 // therefore relaxed to `PartialEq` alone.
 
+impl IndividualValue {
+    /// Returns the number of items in a [`List`](Self::List) value, or
+    /// `None` for any other variant.
+    #[must_use]
+    pub fn list_len(&self) -> Option<usize> {
+        match self {
+            IndividualValue::List(items) => Some(items.len()),
+            _ => None,
+        }
+    }
+
+    /// Returns the IRI at `index` in a [`List`](Self::List) value, or `None`
+    /// if out of bounds or not a list.
+    #[must_use]
+    pub fn list_get(&self, index: usize) -> Option<&'static str> {
+        match self {
+            IndividualValue::List(items) => items.get(index).copied(),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for IndividualValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -357,6 +394,17 @@ impl Ontology {
             + 1
     }
 
+    /// Iterates over every namespace property of the given kind.
+    ///
+    /// Root-level annotation properties (`uor:space`) live in
+    /// [`Ontology::annotation_properties`] and are not included.
+    pub fn properties_of_kind(&self, kind: PropertyKind) -> impl Iterator<Item = &Property> {
+        self.namespaces
+            .iter()
+            .flat_map(|m| m.properties.iter())
+            .filter(move |p| p.kind == kind)
+    }
+
     /// Returns the total number of named individuals across all namespaces.
     #[must_use]
     pub fn individual_count(&self) -> usize {
@@ -488,6 +536,13 @@ pub mod iris {
     /// UOR Foundation base IRI.
     pub const UOR: &str = "https://uor.foundation/";
 
+    /// Builds the full IRI of term `local` in namespace `prefix`
+    /// (e.g., `iri_for("schema", "Ring")` → `"https://uor.foundation/schema/Ring"`).
+    #[must_use]
+    pub fn iri_for(prefix: &str, local: &str) -> String {
+        format!("{UOR}{prefix}/{local}")
+    }
+
     // Namespace IRIs
     /// Content addressing namespace.
     pub const NS_U: &str = "https://uor.foundation/u/";
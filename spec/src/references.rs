@@ -0,0 +1,134 @@
+//! Dangling-reference check over an [`Ontology`].
+//!
+//! Every IRI a term points at — `rdfs:subClassOf`, `owl:disjointWith`,
+//! property `rdfs:domain` / `rdfs:range`, and individual `rdf:type` — must
+//! name a declared term of the right kind, or an allow-listed external
+//! class or datatype. Class-valued fields resolve against class IRIs only;
+//! datatype ranges against [`EXTERNAL_DATATYPES`]. Amendments that rename
+//! or drop a class can leave such references behind;
+//! [`Ontology::validate_references`] catches them.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::model::{iris, Ontology, PropertyKind};
+
+/// A reference from one term to an IRI that does not resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceError {
+    /// IRI of the term holding the reference.
+    pub source: &'static str,
+    /// Which field holds it (e.g., `"subClassOf"`, `"range"`).
+    pub field: &'static str,
+    /// The unresolved IRI.
+    pub target: &'static str,
+}
+
+impl fmt::Display for ReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} -> {} does not resolve",
+            self.source, self.field, self.target
+        )
+    }
+}
+
+impl std::error::Error for ReferenceError {}
+
+/// External classes accepted wherever a class is expected, in full and
+/// prefixed form.
+const EXTERNAL_CLASSES: &[&str] = &[
+    iris::OWL_THING,
+    iris::OWL_CLASS,
+    iris::RDF_LIST,
+    "owl:Thing",
+    "owl:Class",
+    "rdf:List",
+];
+
+/// External datatypes accepted as a datatype-property range, in full and
+/// prefixed form.
+const EXTERNAL_DATATYPES: &[&str] = &[
+    iris::XSD_STRING,
+    iris::XSD_INTEGER,
+    iris::XSD_POSITIVE_INTEGER,
+    iris::XSD_NON_NEGATIVE_INTEGER,
+    iris::XSD_BOOLEAN,
+    iris::XSD_DECIMAL,
+    iris::XSD_DATETIME,
+    iris::XSD_HEX_BINARY,
+    "http://www.w3.org/2001/XMLSchema#anyURI",
+    "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
+    "http://www.w3.org/2001/XMLSchema#float",
+    "http://www.w3.org/2001/XMLSchema#double",
+    "http://www.w3.org/2000/01/rdf-schema#Literal",
+    "xsd:string",
+    "xsd:integer",
+    "xsd:positiveInteger",
+    "xsd:nonNegativeInteger",
+    "xsd:boolean",
+    "xsd:decimal",
+    "xsd:dateTime",
+    "xsd:hexBinary",
+    "xsd:anyURI",
+    "xsd:dateTimeStamp",
+    "xsd:float",
+    "xsd:double",
+    "rdfs:Literal",
+];
+
+impl Ontology {
+    /// Returns every reference that does not name a declared term of the
+    /// expected kind or an allow-listed external class or datatype. Empty
+    /// means the ontology is closed under its references.
+    #[must_use]
+    pub fn validate_references(&self) -> Vec<ReferenceError> {
+        let classes: HashSet<&str> = self
+            .namespaces
+            .iter()
+            .flat_map(|m| m.classes.iter().map(|c| c.id))
+            .chain(EXTERNAL_CLASSES.iter().copied())
+            .collect();
+        let datatypes: HashSet<&str> = EXTERNAL_DATATYPES.iter().copied().collect();
+        let any: HashSet<&str> = classes.union(&datatypes).copied().collect();
+
+        let mut errors = Vec::new();
+        let mut check = |allowed: &HashSet<&str>, source, field, target: &'static str| {
+            if !allowed.contains(target) {
+                errors.push(ReferenceError {
+                    source,
+                    field,
+                    target,
+                });
+            }
+        };
+        for module in &self.namespaces {
+            for class in &module.classes {
+                for parent in class.subclass_of {
+                    check(&classes, class.id, "subClassOf", parent);
+                }
+                for other in class.disjoint_with {
+                    check(&classes, class.id, "disjointWith", other);
+                }
+            }
+            for prop in &module.properties {
+                if let Some(domain) = prop.domain {
+                    check(&classes, prop.id, "domain", domain);
+                }
+                let range_kind = match prop.kind {
+                    PropertyKind::Datatype => &datatypes,
+                    PropertyKind::Object => &classes,
+                    PropertyKind::Annotation => &any,
+                };
+                if !prop.range.is_empty() {
+                    check(range_kind, prop.id, "range", prop.range);
+                }
+            }
+            for ind in &module.individuals {
+                check(&classes, ind.id, "type", ind.type_);
+            }
+        }
+        errors
+    }
+}
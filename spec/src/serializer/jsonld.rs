@@ -6,7 +6,7 @@
 
 use serde_json::{json, Map, Value};
 
-use crate::model::{IndividualValue, Ontology, PropertyKind};
+use crate::model::{IndividualValue, NamespaceModule, Ontology, PropertyKind};
 use crate::serializer::prefixes::STANDARD_PREFIXES;
 
 /// Serializes the complete UOR Foundation ontology to a JSON-LD `Value`.
@@ -26,6 +26,68 @@ pub fn to_json_ld(ontology: &Ontology) -> Value {
     })
 }
 
+/// Serializes a single namespace module to a JSON-LD `Value`.
+///
+/// The `@context` is the full ontology context (so cross-namespace IRIs
+/// still compact), while the `@graph` holds only the module's namespace
+/// declaration and its classes, properties, and individuals. Used to embed
+/// per-namespace metadata in generated web pages.
+#[must_use]
+pub fn module_to_json_ld(ontology: &Ontology, module: &NamespaceModule) -> Value {
+    let mut nodes: Vec<Value> = Vec::new();
+    push_module_nodes(module, &mut nodes);
+    json!({
+        "@context": build_context(ontology),
+        "@graph": Value::Array(nodes)
+    })
+}
+
+/// Builds a standalone JSON-LD `@context` for consumers of UOR data.
+///
+/// Maps every standard and namespace prefix to its IRI, plus one term per
+/// class and property whose local name is unique across the ontology.
+/// Object properties are typed `"@id"` so their values expand as IRIs;
+/// datatype properties carry their XSD range as `@type`. Local names
+/// shared by several namespaces are left out; use `prefix:Name` for those.
+#[must_use]
+pub fn context(ontology: &Ontology) -> Value {
+    let mut ctx = match build_context(ontology) {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+
+    let local = |iri: &'static str| iri.rsplit(['/', '#']).next().unwrap_or(iri);
+    let mut uses: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let classes = ontology.namespaces.iter().flat_map(|m| m.classes.iter());
+    let properties = ontology.namespaces.iter().flat_map(|m| m.properties.iter());
+    for iri in classes.map(|c| c.id).chain(properties.map(|p| p.id)) {
+        *uses.entry(local(iri)).or_default() += 1;
+    }
+    let unique = |name: &str| uses.get(name) == Some(&1) && !ctx.contains_key(name);
+
+    let mut terms: Vec<(&str, Value)> = Vec::new();
+    for class in ontology.namespaces.iter().flat_map(|m| m.classes.iter()) {
+        terms.push((local(class.id), json!(class.id)));
+    }
+    for prop in ontology.properties_of_kind(PropertyKind::Object) {
+        terms.push((local(prop.id), json!({ "@id": prop.id, "@type": "@id" })));
+    }
+    for prop in ontology.properties_of_kind(PropertyKind::Datatype) {
+        terms.push((
+            local(prop.id),
+            json!({ "@id": prop.id, "@type": shorten_iri(prop.range) }),
+        ));
+    }
+    for prop in ontology.properties_of_kind(PropertyKind::Annotation) {
+        terms.push((local(prop.id), json!(prop.id)));
+    }
+    let terms: Vec<(&str, Value)> = terms.into_iter().filter(|(n, _)| unique(n)).collect();
+    for (name, value) in terms {
+        ctx.insert(name.to_owned(), value);
+    }
+    Value::Object(ctx)
+}
+
 fn build_context(ontology: &Ontology) -> Value {
     let mut ctx = Map::new();
     // JSON-LD 1.1 processing mode
@@ -71,42 +133,47 @@ fn build_graph(ontology: &Ontology) -> Value {
 
     // Each namespace module
     for module in &ontology.namespaces {
-        // Namespace ontology declaration
-        let imports: Vec<Value> = module
-            .namespace
-            .imports
-            .iter()
-            .map(|iri| json!({ "@id": iri }))
-            .collect();
-        let mut ns_node = json!({
-            "@id": module.namespace.iri,
-            "@type": "owl:Ontology",
-            "rdfs:label": module.namespace.label,
-            "rdfs:comment": module.namespace.comment,
-            "uor:space": module.namespace.space.as_str()
-        });
-        if !imports.is_empty() {
-            ns_node["owl:imports"] = Value::Array(imports);
-        }
-        nodes.push(ns_node);
+        push_module_nodes(module, &mut nodes);
+    }
 
-        // Classes
-        for class in &module.classes {
-            nodes.push(class_to_json(class));
-        }
+    Value::Array(nodes)
+}
 
-        // Properties
-        for prop in &module.properties {
-            nodes.push(property_to_json(prop));
-        }
+/// Appends the namespace declaration and every term of `module` to `nodes`.
+fn push_module_nodes(module: &NamespaceModule, nodes: &mut Vec<Value>) {
+    // Namespace ontology declaration
+    let imports: Vec<Value> = module
+        .namespace
+        .imports
+        .iter()
+        .map(|iri| json!({ "@id": iri }))
+        .collect();
+    let mut ns_node = json!({
+        "@id": module.namespace.iri,
+        "@type": "owl:Ontology",
+        "rdfs:label": module.namespace.label,
+        "rdfs:comment": module.namespace.comment,
+        "uor:space": module.namespace.space.as_str()
+    });
+    if !imports.is_empty() {
+        ns_node["owl:imports"] = Value::Array(imports);
+    }
+    nodes.push(ns_node);
 
-        // Individuals
-        for ind in &module.individuals {
-            nodes.push(individual_to_json(ind));
-        }
+    // Classes
+    for class in &module.classes {
+        nodes.push(class_to_json(class));
     }
 
-    Value::Array(nodes)
+    // Properties
+    for prop in &module.properties {
+        nodes.push(property_to_json(prop));
+    }
+
+    // Individuals
+    for ind in &module.individuals {
+        nodes.push(individual_to_json(ind));
+    }
 }
 
 fn class_to_json(class: &crate::model::Class) -> Value {
@@ -264,6 +331,27 @@ mod tests {
     use super::*;
     use crate::Ontology;
 
+    #[test]
+    fn standalone_context_maps_prefixes_and_terms() {
+        let ontology = Ontology::full();
+        let ctx = context(ontology);
+        for module in &ontology.namespaces {
+            assert_eq!(
+                ctx[module.namespace.prefix],
+                json!(module.namespace.iri),
+                "prefix {}",
+                module.namespace.prefix
+            );
+        }
+        assert_eq!(ctx["schema"], json!("https://uor.foundation/schema/"));
+        assert_eq!(ctx["Ring"], json!("https://uor.foundation/schema/Ring"));
+        assert_eq!(
+            ctx["ringWittLength"]["@id"],
+            json!("https://uor.foundation/schema/ringWittLength")
+        );
+        assert!(ctx.get("@graph").is_none());
+    }
+
     #[test]
     fn produces_context_and_graph() {
         let ontology = Ontology::full();
@@ -320,6 +408,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn module_graph_is_restricted_to_one_namespace() {
+        let ontology = Ontology::full();
+        let schema = ontology
+            .find_namespace("schema")
+            .expect("schema namespace must exist");
+        let json = module_to_json_ld(ontology, schema);
+        let graph = json["@graph"].as_array().expect("@graph must be array");
+        assert_eq!(
+            graph.len(),
+            1 + schema.classes.len() + schema.properties.len() + schema.individuals.len()
+        );
+        assert!(graph
+            .iter()
+            .any(|n| n["@id"] == "https://uor.foundation/schema/Ring"));
+        assert!(!json["@context"]["schema"].is_null());
+    }
+
     #[test]
     fn contains_amendment_95_terms() {
         let ontology = Ontology::full();
@@ -66,11 +66,13 @@ pub fn to_shacl(ontology: &Ontology) -> String {
         ));
 
         for class in &module.classes {
-            // Collect non-annotation properties in this namespace with
-            // domain matching this class.
-            let props: Vec<_> = module
-                .properties
+            // Collect non-annotation properties with domain matching this
+            // class, including those declared in other namespaces (e.g.
+            // `type:affectsAxis` on `op:Operation`).
+            let props: Vec<_> = ontology
+                .namespaces
                 .iter()
+                .flat_map(|m| m.properties.iter())
                 .filter(|p| {
                     p.domain == Some(class.id) && !matches!(p.kind, PropertyKind::Annotation)
                 })
@@ -210,6 +212,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn every_constrained_class_shape_lists_its_domain_properties() {
+        let ontology = Ontology::full();
+        let shacl = to_shacl(ontology);
+        let prefix_map = build_prefix_map(ontology);
+        for module in &ontology.namespaces {
+            for class in &module.classes {
+                let header = format!("sh:targetClass  {} ;", shorten_iri(class.id, &prefix_map));
+                let start = shacl.find(&header);
+                assert!(start.is_some(), "no NodeShape targets {}", class.id);
+                let shape = &shacl[start.unwrap_or(0)..];
+                let shape = &shape[..shape.find(" .\n").unwrap_or(shape.len())];
+                let constrained = ontology
+                    .namespaces
+                    .iter()
+                    .flat_map(|m| m.properties.iter())
+                    .filter(|p| p.domain == Some(class.id) && p.kind != PropertyKind::Annotation);
+                for prop in constrained {
+                    let path = format!("sh:path {} ;", shorten_iri(prop.id, &prefix_map));
+                    assert!(
+                        shape.contains(&path),
+                        "shape for {} is missing {}",
+                        class.id,
+                        prop.id
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn contains_shapes_prefix() {
         let ontology = Ontology::full();
@@ -171,11 +171,11 @@ pub fn render_concept_from_file(
 
 /// Converts CommonMark markdown to HTML.
 pub fn markdown_to_html(source: &str) -> String {
-    use pulldown_cmark::{html, Options, Parser};
+    use pulldown_cmark::{Options, Parser};
     let opts = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES;
     let parser = Parser::new_ext(source, opts);
     let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
+    uor_docs::renderer::push_html(&mut html_output, parser);
     html_output
 }
 
@@ -61,12 +61,7 @@ pub fn build_search_index(base_path: &str) -> Vec<SearchEntry> {
             entries.push(SearchEntry {
                 label: class.label.to_string(),
                 description: class.comment.to_string(),
-                url: format!(
-                    "{}/namespaces/{}/#class-{}",
-                    base_path,
-                    prefix,
-                    local_name(class.id)
-                ),
+                url: term_url(base_path, prefix, "class", class.local_name()),
                 kind: "class".to_string(),
                 space: space_str.clone(),
                 namespace: prefix.to_string(),
@@ -79,12 +74,7 @@ pub fn build_search_index(base_path: &str) -> Vec<SearchEntry> {
             entries.push(SearchEntry {
                 label: prop.label.to_string(),
                 description: prop.comment.to_string(),
-                url: format!(
-                    "{}/namespaces/{}/#prop-{}",
-                    base_path,
-                    prefix,
-                    local_name(prop.id)
-                ),
+                url: term_url(base_path, prefix, "prop", local_name(prop.id)),
                 kind: "property".to_string(),
                 space: space_str.clone(),
                 namespace: prefix.to_string(),
@@ -102,12 +92,7 @@ pub fn build_search_index(base_path: &str) -> Vec<SearchEntry> {
             entries.push(SearchEntry {
                 label: ind.label.to_string(),
                 description: ind.comment.to_string(),
-                url: format!(
-                    "{}/namespaces/{}/#ind-{}",
-                    base_path,
-                    prefix,
-                    local_name(ind.id)
-                ),
+                url: term_url(base_path, prefix, "ind", local_name(ind.id)),
                 kind: "individual".to_string(),
                 space: space_str.clone(),
                 namespace: prefix.to_string(),
@@ -129,9 +114,18 @@ pub fn generate_explore_data(summaries: &[NamespaceSummary]) -> String {
     }
 }
 
-/// Extracts the local name from an IRI.
-fn local_name(iri: &str) -> &str {
-    iri.rsplit('/').next().unwrap_or(iri)
+/// Builds the search URL for a term: its namespace page plus the row anchor
+/// (`class-`, `prop-`, or `ind-` followed by the local name), so following a
+/// result — by mouse or keyboard — lands directly on the term.
+fn term_url(base_path: &str, prefix: &str, kind: &str, local: &str) -> String {
+    format!("{base_path}/namespaces/{prefix}/index.html#{kind}-{local}")
+}
+
+/// Extracts the local name from an IRI: the text after whichever of `/` or
+/// `#` appears last, so `/`-separated paths and `#`-fragment IRIs (OWL, XSD,
+/// RDF) both yield the term name.
+pub(crate) fn local_name(iri: &str) -> &str {
+    iri.rsplit(['/', '#']).next().unwrap_or(iri)
 }
 
 /// Builds standard breadcrumbs for a namespace page.
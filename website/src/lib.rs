@@ -21,7 +21,7 @@
 //!   index.html
 //!   search.html
 //!   search-index.json
-//!   sitemap.xml
+//!   sitemap.xml            (with <lastmod> only when SOURCE_DATE_EPOCH is set)
 //!   pipeline/index.html
 //!   explore/index.html
 //!   identities/index.html
@@ -34,6 +34,7 @@
 //!     <prefix>/index.html  (one per namespace, 100% auto-generated)
 //!   css/style.css
 //!   js/search.js
+//!   js/theme.js            (theme toggle; used by pages rendered with a theme)
 //! ```
 //!
 //! # Base Path
@@ -69,16 +70,16 @@ use extractor::{
     concept_breadcrumbs, home_breadcrumbs, learn_breadcrumbs, namespace_breadcrumbs,
     namespace_summaries, namespaces_index_breadcrumbs, reference_breadcrumbs, simple_breadcrumbs,
 };
+use model::SitemapEntry;
 use nav::{build_nav, render_nav_bootstrap};
 use renderer::{
     render_about_page, render_citation_page, render_concept_page_body, render_concepts_index,
     render_download_page, render_explore, render_homepage, render_identities_page,
-    render_learn_landing, render_namespace_page, render_namespaces_index, render_page,
-    render_pipeline_page, render_search_page, render_sitemap,
+    render_learn_landing, render_namespace_jsonld, render_namespace_page, render_namespaces_index,
+    render_page, render_page_with_options, render_pipeline_page, render_search_page,
+    render_sitemap, PageOptions, BASE_URL,
 };
 
-const BASE_URL: &str = "https://uor.foundation";
-
 /// Generates the complete website into `out_dir`.
 ///
 /// Reads `PUBLIC_BASE_PATH` from the environment (default: `""`). Set it to
@@ -88,8 +89,8 @@ const BASE_URL: &str = "https://uor.foundation";
 ///
 /// Returns an error if any file cannot be written.
 pub fn generate(out_dir: &Path) -> Result<()> {
-    let base_path = std::env::var("PUBLIC_BASE_PATH").unwrap_or_default();
-    let base_path = base_path.trim_end_matches('/');
+    let base_path = uor_docs::base_path::from_env();
+    let base_path = base_path.as_str();
 
     let nav = build_nav(base_path);
     let summaries = namespace_summaries(base_path);
@@ -114,6 +115,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &home_nav,
         &home_breadcrumbs(base_path),
         base_path,
+        "/",
     );
     writer::write(&out_dir.join("index.html"), &home_html)?;
     sitemap_paths.push("/".to_string());
@@ -128,6 +130,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &search_nav,
         &search_crumbs,
         base_path,
+        "/search.html",
     );
     writer::write(&out_dir.join("search.html"), &search_html)?;
     sitemap_paths.push("/search.html".to_string());
@@ -141,6 +144,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &ns_index_nav,
         &namespaces_index_breadcrumbs(base_path),
         base_path,
+        "/namespaces/",
     );
     writer::write(
         &out_dir.join("namespaces").join("index.html"),
@@ -155,12 +159,18 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         let page_nav = render_nav_bootstrap(&nav, &format!("{}{}", base_path, page_path));
         let ns_breadcrumbs = namespace_breadcrumbs(module.namespace.label, base_path);
         let body = render_namespace_page(module, Some(base_path));
-        let html = render_page(
+        let html = render_page_with_options(
             module.namespace.label,
             &body,
             &page_nav,
             &ns_breadcrumbs,
             base_path,
+            &render_namespace_jsonld(module),
+            &PageOptions {
+                canonical_path: Some(&page_path),
+                description: Some(module.namespace.comment),
+                ..PageOptions::default()
+            },
         );
 
         let out_path = out_dir.join("namespaces").join(prefix).join("index.html");
@@ -193,6 +203,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &pipeline_nav,
         &learn_breadcrumbs("Pipeline", base_path),
         base_path,
+        "/pipeline/",
     );
     writer::write(&out_dir.join("pipeline").join("index.html"), &pipeline_html)?;
     sitemap_paths.push("/pipeline/".to_string());
@@ -206,6 +217,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &explore_nav,
         &reference_breadcrumbs("Explore", base_path),
         base_path,
+        "/explore/",
     );
     writer::write(&out_dir.join("explore").join("index.html"), &explore_html)?;
     sitemap_paths.push("/explore/".to_string());
@@ -219,6 +231,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &identities_nav,
         &reference_breadcrumbs("Identities", base_path),
         base_path,
+        "/identities/",
     );
     writer::write(
         &out_dir.join("identities").join("index.html"),
@@ -235,6 +248,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &download_nav,
         &simple_breadcrumbs("Download", base_path),
         base_path,
+        "/download/",
     );
     writer::write(&out_dir.join("download").join("index.html"), &download_html)?;
     sitemap_paths.push("/download/".to_string());
@@ -248,6 +262,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &citation_nav,
         &simple_breadcrumbs("Citation", base_path),
         base_path,
+        "/citation/",
     );
     writer::write(&out_dir.join("citation").join("index.html"), &citation_html)?;
     sitemap_paths.push("/citation/".to_string());
@@ -261,6 +276,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &about_nav,
         &simple_breadcrumbs("About", base_path),
         base_path,
+        "/about/",
     );
     writer::write(&out_dir.join("about").join("index.html"), &about_html)?;
     sitemap_paths.push("/about/".to_string());
@@ -274,6 +290,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &learn_nav,
         &simple_breadcrumbs("Learn", base_path),
         base_path,
+        "/learn/",
     );
     writer::write(&out_dir.join("learn").join("index.html"), &learn_html)?;
     sitemap_paths.push("/learn/".to_string());
@@ -287,6 +304,7 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &concepts_nav,
         &learn_breadcrumbs("Concepts", base_path),
         base_path,
+        "/concepts/",
     );
     writer::write(&out_dir.join("concepts").join("index.html"), &concepts_html)?;
     sitemap_paths.push("/concepts/".to_string());
@@ -334,12 +352,18 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         );
         let concept_nav = render_nav_bootstrap(&nav, &concept.url);
         let concept_crumbs = concept_breadcrumbs(&concept.title, base_path);
-        let concept_html = render_page(
+        let concept_html = render_page_with_options(
             &concept.title,
             &body,
             &concept_nav,
             &concept_crumbs,
             base_path,
+            "",
+            &PageOptions {
+                canonical_path: Some(&format!("/concepts/{}.html", concept.slug)),
+                description: Some(&concept.description),
+                ..PageOptions::default()
+            },
         );
         let out_path = out_dir
             .join("concepts")
@@ -353,7 +377,12 @@ pub fn generate(out_dir: &Path) -> Result<()> {
     writer::write(&out_dir.join("search-index.json"), &search_index_json)?;
 
     // Sitemap
-    let sitemap_xml = render_sitemap(BASE_URL, &sitemap_paths);
+    let lastmod = build_timestamp(std::env::var("SOURCE_DATE_EPOCH").ok().as_deref());
+    let sitemap_entries: Vec<SitemapEntry> = sitemap_paths
+        .iter()
+        .map(|path| SitemapEntry::new(path, lastmod.as_deref()))
+        .collect();
+    let sitemap_xml = render_sitemap(BASE_URL, &sitemap_entries);
     writer::write(&out_dir.join("sitemap.xml"), &sitemap_xml)?;
 
     // CSS
@@ -364,10 +393,45 @@ pub fn generate(out_dir: &Path) -> Result<()> {
         &out_dir.join("js").join("search.js"),
         &search::search_js(base_path),
     )?;
+    writer::write(&out_dir.join("js").join("theme.js"), renderer::theme_js())?;
 
     Ok(())
 }
 
+/// Returns the sitemap `<lastmod>` timestamp for a `SOURCE_DATE_EPOCH`
+/// value, as RFC 3339 UTC.
+///
+/// `None` when the variable is unset or unparsable: falling back to the
+/// wall clock would make `sitemap.xml` differ on every build, so the
+/// element is omitted instead.
+fn build_timestamp(source_date_epoch: Option<&str>) -> Option<String> {
+    source_date_epoch
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(rfc3339_utc)
+}
+
+/// Formats seconds since the Unix epoch as `YYYY-MM-DDTHH:MM:SSZ`.
+fn rfc3339_utc(secs: u64) -> String {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    // Civil-from-days (proleptic Gregorian), after H. Hinnant.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3_600,
+        (rem % 3_600) / 60,
+        rem % 60
+    )
+}
+
 /// Returns the complete CSS stylesheet.
 fn style_css() -> &'static str {
     include_str!("../static/css/style.css")
@@ -389,6 +453,264 @@ mod tests {
         );
     }
 
+    #[test]
+    fn local_name_splits_on_slash_and_hash() {
+        assert_eq!(
+            extractor::local_name("https://uor.foundation/schema/Ring"),
+            "Ring"
+        );
+        assert_eq!(
+            extractor::local_name("http://www.w3.org/2002/07/owl#Thing"),
+            "Thing"
+        );
+        assert_eq!(
+            extractor::local_name("http://www.w3.org/2001/XMLSchema#string"),
+            "string"
+        );
+    }
+
+    #[test]
+    fn search_index_has_one_entry_per_property_and_individual() {
+        let ontology = uor_ontology::Ontology::full();
+        let entries = extractor::build_search_index("");
+        let of_kind = |kind: &str| entries.iter().filter(|e| e.kind == kind).count();
+        // The global `uor:space` annotation has no namespace page to link to.
+        assert_eq!(of_kind("property"), ontology.property_count() - 1);
+        assert_eq!(of_kind("individual"), ontology.individual_count());
+
+        let indexed: std::collections::HashSet<(&str, &str, &str)> = entries
+            .iter()
+            .map(|e| (e.kind.as_str(), e.label.as_str(), e.url.as_str()))
+            .collect();
+        for module in &ontology.namespaces {
+            let prefix = module.namespace.prefix;
+            for prop in &module.properties {
+                let url = format!(
+                    "/namespaces/{prefix}/index.html#prop-{}",
+                    prop.id.rsplit('/').next().unwrap_or(prop.id)
+                );
+                assert!(
+                    indexed.contains(&("property", prop.label, url.as_str())),
+                    "property {} not searchable by label at {url}",
+                    prop.label
+                );
+            }
+            for ind in &module.individuals {
+                let url = format!(
+                    "/namespaces/{prefix}/index.html#ind-{}",
+                    ind.id.rsplit('/').next().unwrap_or(ind.id)
+                );
+                assert!(
+                    indexed.contains(&("individual", ind.label, url.as_str())),
+                    "individual {} not searchable by label at {url}",
+                    ind.label
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn class_search_url_targets_a_rendered_anchor() {
+        let entries = extractor::build_search_index("");
+        let ring = entries
+            .iter()
+            .find(|e| e.kind == "class" && e.label == "Ring" && e.namespace == "schema");
+        let url = ring.map(|e| e.url.as_str()).unwrap_or("");
+        assert_eq!(url, "/namespaces/schema/index.html#class-Ring");
+
+        // The fragment must be an `id` on the page, as the coverage validator requires.
+        let fragment = url.rsplit('#').next().unwrap_or("");
+        let schema = uor_ontology::Ontology::full().find_namespace("schema");
+        let page = schema
+            .map(|m| renderer::render_namespace_page(m, Some("")))
+            .unwrap_or_default();
+        assert!(page.contains(&format!("id=\"{fragment}\"")));
+        assert!(entries
+            .iter()
+            .filter(|e| e.kind != "namespace")
+            .all(|e| e.url.contains("/index.html#")));
+    }
+
+    #[test]
+    fn multi_word_search_requires_every_token() {
+        let entries = extractor::build_search_index("");
+        let critical = entries
+            .iter()
+            .find(|e| e.kind == "individual" && e.label == "Critical Identity");
+        let score = |q: &str| critical.and_then(|e| search::tokenize_and_match(e, q));
+        // "critical" is a label prefix (80), "identity" a label substring (60).
+        assert_eq!(score("critical identity"), Some(140));
+        assert_eq!(score("Identity   CRITICAL"), Some(140));
+        assert_eq!(score("critical banana"), None);
+        assert_eq!(score("   "), None);
+    }
+
+    #[test]
+    fn index_diff_reports_a_dropped_entry() {
+        let old = extractor::build_search_index("");
+        let mut new = old.clone();
+        let dropped = new.remove(new.len() / 2);
+
+        assert!(search::diff_index(&old, &old).is_empty());
+        let diff = search::diff_index(&old, &new);
+        assert!(diff.added.is_empty());
+        assert_eq!(diff.removed, vec![dropped.url]);
+    }
+
+    #[test]
+    fn pages_link_canonical_url_without_base_path() {
+        let home = render_page("UOR Foundation", "", "", &[], "/UOR-Framework", "/");
+        assert!(home.contains("<link rel=\"canonical\" href=\"https://uor.foundation/\">"));
+
+        let ontology = uor_ontology::Ontology::full();
+        let schema = ontology.find_namespace("schema");
+        let page = schema
+            .map(|m| {
+                renderer::render_page_with_head(
+                    m.namespace.label,
+                    &render_namespace_page(m, Some("")),
+                    "",
+                    &[],
+                    "",
+                    "/namespaces/schema/",
+                    "",
+                )
+            })
+            .unwrap_or_default();
+        assert!(page.contains(
+            "<link rel=\"canonical\" href=\"https://uor.foundation/namespaces/schema/\">"
+        ));
+    }
+
+    #[test]
+    fn schema_page_head_has_preview_meta_from_its_comment() {
+        let ontology = uor_ontology::Ontology::full();
+        let schema = ontology.find_namespace("schema");
+        assert!(schema.is_some());
+        let html = schema
+            .map(|m| {
+                render_page_with_options(
+                    m.namespace.label,
+                    &render_namespace_page(m, Some("")),
+                    "",
+                    &[],
+                    "",
+                    "",
+                    &PageOptions {
+                        description: Some(m.namespace.comment),
+                        ..PageOptions::default()
+                    },
+                )
+            })
+            .unwrap_or_default();
+        let head = html.split("</head>").next().unwrap_or_default();
+        let (label, comment) = schema
+            .map(|m| (m.namespace.label, m.namespace.comment))
+            .unwrap_or_default();
+        assert!(head.contains(&format!(
+            "<meta property=\"og:title\" content=\"{}\">",
+            renderer::escape_html(label)
+        )));
+        assert!(head.contains(&format!(
+            "<meta property=\"og:description\" content=\"{}\">",
+            renderer::escape_html(comment)
+        )));
+        assert!(head.contains("<meta property=\"og:type\" content=\"website\">"));
+        assert!(head.contains("<meta name=\"twitter:card\" content=\"summary\">"));
+    }
+
+    #[test]
+    fn theme_option_adds_data_theme_and_toggle_button() {
+        let plain = render_page("Home", "<p>x</p>", "", &[], "", "/");
+        assert!(!plain.contains("data-theme"));
+        assert!(!plain.contains("theme-toggle"));
+
+        let options = renderer::PageOptions {
+            theme: Some("dark"),
+            ..renderer::PageOptions::default()
+        };
+        let html =
+            renderer::render_page_with_options("Home", "<p>x</p>", "", &[], "/b", "", &options);
+        assert!(html.contains("<html lang=\"en\" data-theme=\"dark\">"));
+        assert!(html.contains("<button type=\"button\" id=\"theme-toggle\""));
+        assert!(html.contains("<script src=\"/b/js/theme.js\" defer></script>"));
+        assert!(renderer::theme_js().contains("getElementById('theme-toggle')"));
+    }
+
+    #[test]
+    fn build_timestamp_requires_source_date_epoch() {
+        assert_eq!(
+            build_timestamp(Some("1767225600")).as_deref(),
+            Some("2026-01-01T00:00:00Z")
+        );
+        assert_eq!(build_timestamp(None), None);
+        assert_eq!(build_timestamp(Some("not-a-number")), None);
+    }
+
+    #[test]
+    fn sitemap_has_one_url_per_entry_with_valid_priority() {
+        let entries = vec![
+            SitemapEntry::new("/", Some("2026-01-01T00:00:00Z")),
+            SitemapEntry::new("/namespaces/schema/", Some("2026-01-01T00:00:00Z")),
+            SitemapEntry::new("/search.html", None),
+        ];
+        let xml = render_sitemap(BASE_URL, &entries);
+        assert_eq!(xml.matches("<url>").count(), entries.len());
+        assert_eq!(xml.matches("<lastmod>").count(), 2);
+        assert!(xml.contains("<loc>https://uor.foundation/</loc><lastmod>2026-01-01T00:00:00Z</lastmod><priority>1.0</priority>"));
+        assert!(xml.contains("<priority>0.8</priority>"));
+        for chunk in xml.split("<priority>").skip(1) {
+            let value: f32 = chunk
+                .split("</priority>")
+                .next()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(-1.0);
+            assert!(
+                (0.0..=1.0).contains(&value),
+                "priority out of range: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn rfc3339_formats_known_instants() {
+        assert_eq!(rfc3339_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339_utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339_utc(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn namespace_page_embeds_parseable_jsonld() {
+        let ontology = Ontology::full();
+        let schema = ontology.find_namespace("schema");
+        assert!(schema.is_some());
+        let html = schema
+            .map(|m| {
+                renderer::render_page_with_head(
+                    m.namespace.label,
+                    &render_namespace_page(m, None),
+                    "",
+                    &home_breadcrumbs(""),
+                    "",
+                    "/namespaces/schema/",
+                    &render_namespace_jsonld(m),
+                )
+            })
+            .unwrap_or_default();
+        let open = "<script type=\"application/ld+json\">";
+        let head = html.split("</head>").next().unwrap_or_default();
+        let payload = head
+            .split(open)
+            .nth(1)
+            .and_then(|rest| rest.split("</script>").next())
+            .unwrap_or_default();
+        let json: serde_json::Value = serde_json::from_str(payload).unwrap_or_default();
+        let graph = json["@graph"].as_array().cloned().unwrap_or_default();
+        assert!(graph
+            .iter()
+            .any(|n| n["@id"] == "https://uor.foundation/schema/Ring"));
+    }
+
     #[test]
     fn namespace_summaries_count() {
         let summaries = namespace_summaries("");
@@ -25,7 +25,7 @@ pub struct BreadcrumbItem {
 }
 
 /// An entry in the JSON search index.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SearchEntry {
     /// Display label for search results.
     pub label: String,
@@ -43,6 +43,54 @@ pub struct SearchEntry {
     pub subkind: String,
 }
 
+/// Entries added and removed between two builds of the search index,
+/// identified by URL and listed in sorted order.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct IndexDiff {
+    /// URLs present only in the new index.
+    pub added: Vec<String>,
+    /// URLs present only in the old index — terms that dropped out of search.
+    pub removed: Vec<String>,
+}
+
+impl IndexDiff {
+    /// Returns `true` if both indexes cover the same URLs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// An entry in `sitemap.xml`.
+#[derive(Debug, Serialize)]
+pub struct SitemapEntry {
+    /// Absolute path from site root (e.g. `/namespaces/schema/`).
+    pub path: String,
+    /// Last modification time (RFC 3339), omitted from the XML when `None`.
+    pub lastmod: Option<String>,
+    /// Crawl priority in `[0.0, 1.0]`.
+    pub priority: f32,
+}
+
+impl SitemapEntry {
+    /// Creates an entry with the default priority for `path`: 1.0 for the
+    /// homepage, 0.8 for namespace pages, 0.5 for everything else.
+    pub fn new(path: &str, lastmod: Option<&str>) -> Self {
+        let priority = if path == "/" {
+            1.0
+        } else if path.starts_with("/namespaces/") && path != "/namespaces/" {
+            0.8
+        } else {
+            0.5
+        };
+        Self {
+            path: path.to_string(),
+            lastmod: lastmod.map(str::to_string),
+            priority,
+        }
+    }
+}
+
 /// Summary of a namespace for the homepage grid.
 #[derive(Debug, Serialize)]
 pub struct NamespaceSummary {
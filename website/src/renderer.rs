@@ -2,33 +2,139 @@
 //!
 //! All HTML is generated directly in Rust for determinism and zero dependencies.
 
-pub use uor_docs::renderer::escape_html;
+pub use uor_docs::renderer::{canonical_link, escape_html, BASE_URL};
 use uor_ontology::{IndividualValue, NamespaceModule, Ontology, PropertyKind};
 
-use crate::model::{BreadcrumbItem, ConceptPage, NamespaceSummary};
+use crate::extractor::local_name;
+use crate::model::{BreadcrumbItem, ConceptPage, NamespaceSummary, SitemapEntry};
 
 /// Renders a complete HTML page using the site layout.
+///
+/// `page_path` is the page's path from the site root without the base path
+/// (e.g. `/namespaces/schema/`); it becomes the `<link rel="canonical">`.
 pub fn render_page(
     title: &str,
     body: &str,
     nav_html: &str,
     breadcrumbs: &[BreadcrumbItem],
     base_path: &str,
+    page_path: &str,
+) -> String {
+    render_page_with_head(title, body, nav_html, breadcrumbs, base_path, page_path, "")
+}
+
+/// Renders a complete HTML page, inserting `head_html` at the end of `<head>`.
+///
+/// `head_html` is emitted verbatim; callers are responsible for escaping.
+pub fn render_page_with_head(
+    title: &str,
+    body: &str,
+    nav_html: &str,
+    breadcrumbs: &[BreadcrumbItem],
+    base_path: &str,
+    page_path: &str,
+    head_html: &str,
+) -> String {
+    render_page_with_options(
+        title,
+        body,
+        nav_html,
+        breadcrumbs,
+        base_path,
+        head_html,
+        &PageOptions {
+            canonical_path: Some(page_path),
+            ..PageOptions::default()
+        },
+    )
+}
+
+/// Optional page-layout features, all off by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PageOptions<'a> {
+    /// Initial colour theme (e.g. `"dark"` or `"light"`). When set, the page
+    /// gets `<html data-theme="…">`, a `<button id="theme-toggle">` in the
+    /// header, and loads `js/theme.js` (see [`theme_js`]), which flips the
+    /// attribute and remembers the choice.
+    ///
+    /// The stylesheet must style `[data-theme="dark"]` and
+    /// `[data-theme="light"]` on the root element, and `.theme-toggle` for
+    /// the button.
+    pub theme: Option<&'a str>,
+    /// Path of the page from the site root, without the base path. When set,
+    /// `<head>` gets a `<link rel="canonical">` to [`BASE_URL`] plus this
+    /// path, so mirrors served under another prefix defer to the main site.
+    pub canonical_path: Option<&'a str>,
+    /// Summary used for the Open Graph / Twitter Card description (e.g. a
+    /// namespace `comment`). Defaults to [`SITE_DESCRIPTION`].
+    pub description: Option<&'a str>,
+}
+
+/// Site tagline, used as the link-preview description of pages without
+/// their own summary.
+pub const SITE_DESCRIPTION: &str = "Universal Object Reference — a formal ontology for \
+     content-addressed, algebraically-structured object spaces.";
+
+/// Renders Open Graph and Twitter Card `<meta>` tags so shared links show
+/// a rich preview with `title` and `description`.
+pub fn render_meta_tags(title: &str, description: &str) -> String {
+    let title = escape_html(title);
+    let description = escape_html(description);
+    format!(
+        "<meta name=\"description\" content=\"{description}\">\n\
+         <meta property=\"og:title\" content=\"{title}\">\n\
+         <meta property=\"og:description\" content=\"{description}\">\n\
+         <meta property=\"og:type\" content=\"website\">\n\
+         <meta property=\"og:site_name\" content=\"UOR Foundation\">\n\
+         <meta name=\"twitter:card\" content=\"summary\">\n\
+         <meta name=\"twitter:title\" content=\"{title}\">\n\
+         <meta name=\"twitter:description\" content=\"{description}\">\n"
+    )
+}
+
+/// Renders a complete HTML page with `head_html` and the layout features
+/// selected in `options`.
+pub fn render_page_with_options(
+    title: &str,
+    body: &str,
+    nav_html: &str,
+    breadcrumbs: &[BreadcrumbItem],
+    base_path: &str,
+    head_html: &str,
+    options: &PageOptions<'_>,
 ) -> String {
     let crumb_html = render_breadcrumbs(breadcrumbs);
     let home_url = format!("{}/", base_path);
     let css_url = format!("{}/css/style.css", base_path);
     let js_url = format!("{}/js/search.js", base_path);
+    let canonical = options
+        .canonical_path
+        .map(canonical_link)
+        .unwrap_or_default();
+    let meta_tags = render_meta_tags(title, options.description.unwrap_or(SITE_DESCRIPTION));
+    let (theme_attr, theme_button, theme_script) = match options.theme {
+        Some(theme) => (
+            format!(" data-theme=\"{}\"", escape_html(theme)),
+            "<button type=\"button\" id=\"theme-toggle\" class=\"theme-toggle\" \
+             aria-label=\"Toggle colour theme\">&#9680;</button>\n"
+                .to_string(),
+            format!(
+                "<script src=\"{}\" defer></script>\n",
+                escape_html(&format!("{base_path}/js/theme.js"))
+            ),
+        ),
+        None => (String::new(), String::new(), String::new()),
+    };
     format!(
         r##"<!DOCTYPE html>
-<html lang="en">
+<html lang="en"{theme_attr}>
 <head>
 <meta charset="utf-8">
 <meta name="viewport" content="width=device-width, initial-scale=1.0">
 <title>{title} — UOR Foundation</title>
-<link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.3/dist/css/bootstrap.min.css" rel="stylesheet" integrity="sha384-QWTKZyjpPEjISv5WaRU9OFeRpok6YctnYmDr5pNlyT2bRjXh0JMhjY6hW+ALEwIH" crossorigin="anonymous">
+{meta_tags}<link href="https://cdn.jsdelivr.net/npm/bootstrap@5.3.3/dist/css/bootstrap.min.css" rel="stylesheet" integrity="sha384-QWTKZyjpPEjISv5WaRU9OFeRpok6YctnYmDr5pNlyT2bRjXh0JMhjY6hW+ALEwIH" crossorigin="anonymous">
 <link rel="stylesheet" href="{css_url}">
-</head>
+{canonical}{head_html}</head>
 <body>
 <a href="#main-content" class="skip-link">Skip to main content</a>
 <header class="navbar navbar-expand-lg navbar-dark site-header">
@@ -41,7 +147,7 @@ pub fn render_page(
 <nav aria-label="Site navigation" class="site-nav me-auto">
 {nav_html}
 </nav>
-<a href="https://github.com/UOR-Foundation/UOR-Framework" class="github-link" target="_blank" rel="noopener">&#9733; Star on GitHub</a>
+{theme_button}<a href="https://github.com/UOR-Foundation/UOR-Framework" class="github-link" target="_blank" rel="noopener">&#9733; Star on GitHub</a>
 </div>
 </div>
 </header>
@@ -58,11 +164,12 @@ pub fn render_page(
 </footer>
 <script src="https://cdn.jsdelivr.net/npm/bootstrap@5.3.3/dist/js/bootstrap.bundle.min.js" integrity="sha384-YvpcrYf0tY3lHB60NNkmXc5s9fDVZLESaAA55NDzOxhy9GkcIdslK1eN7N6jIeHz" crossorigin="anonymous"></script>
 <script src="{js_url}" defer></script>
-</body>
+{theme_script}</body>
 </html>"##,
         title = escape_html(title),
         css_url = escape_html(&css_url),
         home_url = escape_html(&home_url),
+        head_html = head_html,
         nav_html = nav_html,
         crumb_html = crumb_html,
         body = body,
@@ -70,6 +177,38 @@ pub fn render_page(
     )
 }
 
+/// Returns `js/theme.js`: toggles `data-theme` on the root element between
+/// `dark` and `light` when `#theme-toggle` is clicked, persisting the choice
+/// in `localStorage`.
+pub fn theme_js() -> &'static str {
+    r"// UOR Foundation — colour theme toggle
+(function () {
+  'use strict';
+  var root = document.documentElement;
+  var saved = null;
+  try { saved = localStorage.getItem('uor-theme'); } catch (e) {}
+  if (saved === 'dark' || saved === 'light') root.setAttribute('data-theme', saved);
+  var button = document.getElementById('theme-toggle');
+  if (!button) return;
+  button.addEventListener('click', function () {
+    var next = root.getAttribute('data-theme') === 'light' ? 'dark' : 'light';
+    root.setAttribute('data-theme', next);
+    try { localStorage.setItem('uor-theme', next); } catch (e) {}
+  });
+}());
+"
+}
+
+/// Renders a `<script type="application/ld+json">` block describing one namespace.
+///
+/// The payload is the spec's JSON-LD serialization restricted to `module`.
+/// `</` is escaped as `<\/` so comment text can never close the script element.
+pub fn render_namespace_jsonld(module: &NamespaceModule) -> String {
+    let value = uor_ontology::serializer::jsonld::module_to_json_ld(Ontology::full(), module);
+    let json = value.to_string().replace("</", "<\\/");
+    format!("<script type=\"application/ld+json\">{json}</script>\n")
+}
+
 /// Renders breadcrumb navigation as an ordered list.
 pub fn render_breadcrumbs(crumbs: &[BreadcrumbItem]) -> String {
     let mut html = String::from("<ol>\n");
@@ -287,7 +426,7 @@ pub fn render_namespaces_index(summaries: &[NamespaceSummary], base_path: &str)
 <h2>All Namespaces</h2>
 <table>
 <thead>
-<tr><th>Prefix</th><th>Label</th><th>Classes</th><th>Properties</th><th>Individuals</th><th>Space</th></tr>
+<tr><th scope="col">Prefix</th><th scope="col">Label</th><th scope="col">Classes</th><th scope="col">Properties</th><th scope="col">Individuals</th><th scope="col">Space</th></tr>
 </thead>
 <tbody>
 {rows}
@@ -351,9 +490,9 @@ pub fn render_namespace_page(module: &NamespaceModule, base_path: Option<&str>)
     // Classes
     if !module.classes.is_empty() {
         body.push_str("<h2>Classes</h2>\n<table>\n");
-        body.push_str("<thead><tr><th>Name</th><th>Subclass Of</th><th>Disjoint With</th><th>Comment</th></tr></thead>\n<tbody>\n");
+        body.push_str("<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">Subclass Of</th><th scope=\"col\">Disjoint With</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n");
         for class in &module.classes {
-            let local = local_name(class.id);
+            let local = class.local_name();
             body.push_str(&format!(
                 "<tr id=\"class-{local}\"><td><code>{label}</code></td><td>{parents}</td><td>{disjoints}</td><td>{comment}</td></tr>\n",
                 local = escape_html(local),
@@ -369,7 +508,7 @@ pub fn render_namespace_page(module: &NamespaceModule, base_path: Option<&str>)
     // Properties
     if !module.properties.is_empty() {
         body.push_str("<h2>Properties</h2>\n<table>\n");
-        body.push_str("<thead><tr><th>Name</th><th>Kind</th><th>Functional</th><th>Domain</th><th>Range</th><th>Comment</th></tr></thead>\n<tbody>\n");
+        body.push_str("<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">Kind</th><th scope=\"col\">Functional</th><th scope=\"col\">Domain</th><th scope=\"col\">Range</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n");
         for prop in &module.properties {
             let local = local_name(prop.id);
             let kind = match prop.kind {
@@ -395,7 +534,7 @@ pub fn render_namespace_page(module: &NamespaceModule, base_path: Option<&str>)
     if !module.individuals.is_empty() {
         body.push_str("<h2>Named Individuals</h2>\n<table>\n");
         body.push_str(
-            "<thead><tr><th>Name</th><th>Type</th><th>Comment</th></tr></thead>\n<tbody>\n",
+            "<thead><tr><th scope=\"col\">Name</th><th scope=\"col\">Type</th><th scope=\"col\">Comment</th></tr></thead>\n<tbody>\n",
         );
         for ind in &module.individuals {
             let local = local_name(ind.id);
@@ -498,13 +637,27 @@ pub fn render_search_page(base_path: &str) -> String {
 }
 
 /// Renders the sitemap.xml content.
-pub fn render_sitemap(base_url: &str, paths: &[String]) -> String {
+///
+/// Each entry emits `<loc>`, an optional `<lastmod>`, and a `<priority>`
+/// clamped to `[0.0, 1.0]`.
+pub fn render_sitemap(base_url: &str, entries: &[SitemapEntry]) -> String {
     let mut xml = String::from(
         "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
          <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
     );
-    for path in paths {
-        xml.push_str(&format!("  <url><loc>{}{}</loc></url>\n", base_url, path));
+    for entry in entries {
+        let lastmod = entry
+            .lastmod
+            .as_deref()
+            .map(|t| format!("<lastmod>{}</lastmod>", escape_html(t)))
+            .unwrap_or_default();
+        xml.push_str(&format!(
+            "  <url><loc>{}{}</loc>{}<priority>{:.1}</priority></url>\n",
+            base_url,
+            escape_html(&entry.path),
+            lastmod,
+            entry.priority.clamp(0.0, 1.0)
+        ));
     }
     xml.push_str("</urlset>\n");
     xml
@@ -525,7 +678,7 @@ pub fn render_download_page(base_path: &str) -> String {
          All files are generated from the same authoritative source in \
          <code>spec/</code>.</p>\n\
          <table class=\"download-table\">\n\
-         <thead><tr><th>Format</th><th>Description</th><th>Download</th></tr></thead>\n\
+         <thead><tr><th scope=\"col\">Format</th><th scope=\"col\">Description</th><th scope=\"col\">Download</th></tr></thead>\n\
          <tbody>\n\
          <tr>\n\
          <td><span class=\"format-badge\">.jsonld</span></td>\n\
@@ -783,7 +936,7 @@ pub fn render_identities_page(ontology: &Ontology, base_path: &str) -> String {
          <table id=\"identity-table\">\n\
          <thead>\n\
          <tr>\n\
-         <th>ID</th><th>Label</th><th>Domain</th><th>Comment</th>\n\
+         <th scope=\"col\">ID</th><th scope=\"col\">Label</th><th scope=\"col\">Domain</th><th scope=\"col\">Comment</th>\n\
          </tr>\n\
          </thead>\n\
          <tbody>\n"
@@ -1338,11 +1491,3 @@ fn concept_title(slug: &str) -> String {
         .map(|(_, title, _)| (*title).to_string())
         .unwrap_or_else(|| slug.replace('-', " "))
 }
-
-/// Extracts the local name from an IRI.
-///
-/// Handles both `/`-separated paths and `#`-fragment IRIs (OWL, XSD, RDF).
-fn local_name(iri: &str) -> &str {
-    let after_slash = iri.rsplit('/').next().unwrap_or(iri);
-    after_slash.rsplit('#').next().unwrap_or(after_slash)
-}
@@ -1,8 +1,41 @@
 //! Generates the JSON search index and search.js client.
 
+use std::collections::BTreeSet;
+
 use anyhow::Result;
 
 use crate::extractor::build_search_index;
+use crate::model::{IndexDiff, SearchEntry};
+
+/// Scores `entry` against a multi-word `query`, or returns `None` if any
+/// word fails to match.
+///
+/// The query is lowercased and split on whitespace; every token must match
+/// (AND semantics). Each token scores 100 for an exact label match, 80 for a
+/// label prefix, 60 for a label substring, and 20 for a description
+/// substring; the entry's score is the sum. The `score` function in
+/// [`search_js`] implements the same algorithm client-side.
+pub fn tokenize_and_match(entry: &SearchEntry, query: &str) -> Option<u32> {
+    let label = entry.label.to_lowercase();
+    let description = entry.description.to_lowercase();
+    let mut total = None;
+    for token in query.split_whitespace() {
+        let token = token.to_lowercase();
+        let score = if label == token {
+            100
+        } else if label.starts_with(&token) {
+            80
+        } else if label.contains(&token) {
+            60
+        } else if description.contains(&token) {
+            20
+        } else {
+            return None;
+        };
+        total = Some(total.unwrap_or(0) + score);
+    }
+    total
+}
 
 /// Generates the JSON search index as a serialized string.
 ///
@@ -16,6 +49,23 @@ pub fn generate_search_index(base_path: &str) -> Result<String> {
     Ok(json)
 }
 
+/// Compares two search indexes by entry URL (which carries the term's
+/// namespace and anchor), reporting what a rebuild added and dropped.
+pub fn diff_index(old: &[SearchEntry], new: &[SearchEntry]) -> IndexDiff {
+    let old_urls: BTreeSet<&str> = old.iter().map(|e| e.url.as_str()).collect();
+    let new_urls: BTreeSet<&str> = new.iter().map(|e| e.url.as_str()).collect();
+    IndexDiff {
+        added: new_urls
+            .difference(&old_urls)
+            .map(|u| u.to_string())
+            .collect(),
+        removed: old_urls
+            .difference(&new_urls)
+            .map(|u| u.to_string())
+            .collect(),
+    }
+}
+
 /// Returns the client-side search JavaScript with scoring, facets, and kind badges.
 pub fn search_js(base_path: &str) -> String {
     format!(
@@ -38,14 +88,21 @@ pub fn search_js(base_path: &str) -> String {
     .then(function (data) {{ index = data; }})
     .catch(function (e) {{ console.error('Failed to load search index:', e); }});
 
-  // Scoring: exact label match > prefix match > label contains > description contains
+  // Scoring mirrors search::tokenize_and_match: every whitespace-separated
+  // token must match; per token, exact label (100) > label prefix (80) >
+  // label contains (60) > description contains (20); scores are summed.
   function score(entry, q) {{
+    var tokens = q.split(/\s+/).filter(function (t) {{ return t.length > 0; }});
     var l = entry.label.toLowerCase();
-    if (l === q)             return 100;
-    if (l.startsWith(q))     return 80;
-    if (l.includes(q))       return 60;
-    if ((entry.description||'').toLowerCase().includes(q)) return 20;
-    return 0;
+    var d = (entry.description||'').toLowerCase();
+    var total = 0;
+    for (var i = 0; i < tokens.length; i++) {{
+      var t = tokens[i];
+      var s = l === t ? 100 : l.startsWith(t) ? 80 : l.includes(t) ? 60 : d.includes(t) ? 20 : 0;
+      if (s === 0) return 0;
+      total += s;
+    }}
+    return total;
   }}
 
   function createBadge(cls, text) {{
@@ -8,6 +8,7 @@
 use std::collections::HashMap;
 
 use uor_ontology::{
+    iris,
     model::{IndividualValue, NamespaceModule, Space},
     Ontology,
 };
@@ -193,7 +194,7 @@ pub fn render_namespace_dependency_graph_svg(ontology: &Ontology, base_path: &st
             .filter_map(|iri| {
                 all_prefixes
                     .iter()
-                    .find(|&&p| iri.contains(&format!("/{p}/")))
+                    .find(|&&p| **iri == iris::iri_for(p, ""))
                     .copied()
             })
             .collect();
@@ -248,7 +249,7 @@ pub fn render_namespace_dependency_graph_svg(ontology: &Ontology, base_path: &st
             for import_iri in m.namespace.imports {
                 let found = all_prefixes
                     .iter()
-                    .find(|&&p| import_iri.contains(&format!("/{p}/")));
+                    .find(|&&p| **import_iri == iris::iri_for(p, ""));
                 if let Some(&to_prefix) = found {
                     if let (Some(&tx), Some(&ty)) = (cx_of.get(to_prefix), cy_of.get(to_prefix)) {
                         let x1 = tx + SVG_NODE_W / 2.0;